@@ -0,0 +1,124 @@
+// Minimal stand-in for a Sputnik DAO v2 contract, for exercising DAO-owned
+// NEARShield campaigns locally. Only FunctionCall proposals are supported and
+// a single council member's approval executes them immediately.
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::Vector;
+use near_sdk::json_types::{Base64VecU8, U128, U64};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, near_bindgen, require, AccountId, Gas, PanicOnDefault, Promise};
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ActionCall {
+    pub method_name: String,
+    pub args: Base64VecU8,
+    pub deposit: U128,
+    pub gas: U64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ProposalKind {
+    FunctionCall {
+        receiver_id: AccountId,
+        actions: Vec<ActionCall>,
+    },
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ProposalInput {
+    pub description: String,
+    pub kind: ProposalKind,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ProposalStatus {
+    InProgress,
+    Approved,
+    Rejected,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Proposal {
+    pub proposer: AccountId,
+    pub description: String,
+    pub kind: ProposalKind,
+    pub status: ProposalStatus,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Action {
+    VoteApprove,
+    VoteReject,
+}
+
+#[near_bindgen]
+#[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
+pub struct MockDao {
+    council: Vec<AccountId>,
+    proposals: Vector<Proposal>,
+}
+
+#[near_bindgen]
+impl MockDao {
+    #[init]
+    pub fn new(council: Vec<AccountId>) -> Self {
+        Self {
+            council,
+            proposals: Vector::new(b"p"),
+        }
+    }
+
+    pub fn add_proposal(&mut self, proposal: ProposalInput) -> u64 {
+        self.assert_council();
+        self.proposals.push(&Proposal {
+            proposer: env::predecessor_account_id(),
+            description: proposal.description,
+            kind: proposal.kind,
+            status: ProposalStatus::InProgress,
+        });
+        self.proposals.len() - 1
+    }
+
+    // Same call shape as Sputnik's `act_proposal(id, action, memo)`
+    pub fn act_proposal(&mut self, id: u64, action: Action, memo: Option<String>) -> Option<Promise> {
+        let _ = memo;
+        self.assert_council();
+        let mut proposal = self.proposals.get(id).expect("Proposal not found");
+        require!(proposal.status == ProposalStatus::InProgress, "Proposal already decided");
+
+        let approved = matches!(action, Action::VoteApprove);
+        proposal.status = if approved { ProposalStatus::Approved } else { ProposalStatus::Rejected };
+        self.proposals.replace(id, &proposal);
+        if !approved {
+            return None;
+        }
+
+        let ProposalKind::FunctionCall { receiver_id, actions } = proposal.kind;
+        let mut promise = Promise::new(receiver_id);
+        for action in actions {
+            promise = promise.function_call(
+                action.method_name,
+                action.args.into(),
+                action.deposit.0,
+                Gas(action.gas.0),
+            );
+        }
+        Some(promise)
+    }
+
+    pub fn get_proposal(&self, id: u64) -> Option<Proposal> {
+        self.proposals.get(id)
+    }
+
+    fn assert_council(&self) {
+        require!(
+            self.council.contains(&env::predecessor_account_id()),
+            "Only council members"
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use near_sdk::{env, require, AccountId, CurveType, PublicKey};
+use near_sdk::collections::UnorderedSet;
+use crate::*;
+
+#[near_bindgen]
+impl Contract {
+    // Private campaigns: owner manages which researchers may submit
+    pub fn add_campaign_invites(&mut self, campaign_id: u64, account_ids: Vec<AccountId>) {
+        self.assert_campaign_owner(campaign_id);
+        require!(!self.paused, "Contract paused");
+        let campaign = self.campaigns.get(&campaign_id).expect("Campaign not found");
+        require!(campaign.campaign_type == CampaignType::Private, "Campaign is not private");
+
+        self.internal_add_invites(campaign_id, &account_ids);
+        emit_campaign_invites_changed(campaign_id, &account_ids, true);
+    }
+
+    pub fn remove_campaign_invites(&mut self, campaign_id: u64, account_ids: Vec<AccountId>) {
+        self.assert_campaign_owner(campaign_id);
+        require!(!self.paused, "Contract paused");
+
+        if let Some(mut invites) = self.campaign_invites.get(&campaign_id) {
+            for account_id in &account_ids {
+                invites.remove(account_id);
+            }
+            self.campaign_invites.insert(&campaign_id, &invites);
+        }
+        emit_campaign_invites_changed(campaign_id, &account_ids, false);
+    }
+
+    // Key used to verify off-chain invite vouchers; None disables vouchers.
+    // Rotating the key invalidates every voucher signed with the old one.
+    pub fn set_campaign_voucher_key(&mut self, campaign_id: u64, public_key: Option<PublicKey>) {
+        self.assert_campaign_owner(campaign_id);
+        require!(!self.paused, "Contract paused");
+        let mut campaign = self.campaigns.get(&campaign_id).expect("Campaign not found");
+        require!(campaign.campaign_type == CampaignType::Private, "Campaign is not private");
+        if let Some(key) = &public_key {
+            require!(key.curve_type() == CurveType::ED25519, "Voucher key must be ed25519");
+        }
+
+        campaign.voucher_key = public_key;
+        self.campaigns.insert(&campaign_id, &campaign);
+        emit_campaign_voucher_key_set(campaign_id, campaign.voucher_key.as_ref());
+    }
+
+    pub fn is_voucher_spent(&self, campaign_id: u64, nonce: u64) -> bool {
+        self.spent_voucher_nonces.contains(&(campaign_id, nonce))
+    }
+
+    pub fn check_eligibility(&self, campaign_id: u64, account_id: AccountId) -> EligibilityCheck {
+        let campaign = self.campaigns.get(&campaign_id).expect("Campaign not found");
+        let reasons = self.eligibility_failures(&campaign, &account_id);
+        EligibilityCheck {
+            eligible: reasons.is_empty(),
+            reasons,
+        }
+    }
+
+    pub fn get_campaign_invites(&self, campaign_id: u64, from_index: Option<u64>, limit: Option<u64>) -> Vec<AccountId> {
+        if let Some(invites) = self.campaign_invites.get(&campaign_id) {
+            let from = from_index.unwrap_or(0);
+            let limit = limit.unwrap_or(10);
+            invites
+                .iter()
+                .skip(from as usize)
+                .take(limit as usize)
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
+    pub fn is_invited(&self, campaign_id: u64, account_id: AccountId) -> bool {
+        self.campaign_invites
+            .get(&campaign_id)
+            .map_or(false, |invites| invites.contains(&account_id))
+    }
+}
+
+impl Contract {
+    pub(crate) fn assert_can_submit(
+        &mut self,
+        campaign: &Campaign,
+        account_id: &AccountId,
+        voucher: Option<SignedInviteVoucher>,
+    ) {
+        let failures = self.eligibility_failures(campaign, account_id);
+        require!(failures.is_empty(), failures.join("; "));
+
+        if campaign.campaign_type != CampaignType::Private {
+            return;
+        }
+        if self.is_invited(campaign.id, account_id.clone()) {
+            return;
+        }
+        let voucher = voucher.expect("Not invited to this private campaign");
+        self.internal_redeem_voucher(campaign, account_id, voucher);
+    }
+
+    fn eligibility_failures(&self, campaign: &Campaign, account_id: &AccountId) -> Vec<String> {
+        let rules = match &campaign.eligibility {
+            Some(rules) => rules,
+            None => return vec![],
+        };
+        let stats = self.finder_stats.get(account_id).unwrap_or_default();
+        let mut reasons = vec![];
+
+        if let Some(min) = rules.min_accepted_bugs {
+            if stats.total_bugs_found < min {
+                reasons.push(format!("Requires {} accepted bugs, has {}", min, stats.total_bugs_found));
+            }
+        }
+        if let Some(min) = rules.min_severity_score {
+            if stats.total_severity_score < min {
+                reasons.push(format!("Requires severity score {}, has {}", min, stats.total_severity_score));
+            }
+        }
+        if let Some(max_pct) = rules.max_rejection_ratio_pct {
+            let reviewed = stats.total_bugs_found + stats.total_bugs_rejected;
+            if reviewed > 0 {
+                let ratio_pct = stats.total_bugs_rejected as u64 * 100 / reviewed as u64;
+                if ratio_pct > max_pct as u64 {
+                    reasons.push(format!("Rejection ratio {}% exceeds {}%", ratio_pct, max_pct));
+                }
+            }
+        }
+        reasons
+    }
+
+    // A redeemed voucher adds the submitter to the invite list, so later
+    // submissions do not need a new voucher
+    fn internal_redeem_voucher(&mut self, campaign: &Campaign, account_id: &AccountId, signed: SignedInviteVoucher) {
+        let voucher = signed.voucher;
+        let key = campaign.voucher_key.as_ref().expect("Campaign does not accept vouchers");
+        require!(voucher.campaign_id == campaign.id, "Voucher is for another campaign");
+        require!(env::block_timestamp_ms() < voucher.expires_at, "Voucher expired");
+        if let Some(invitee) = &voucher.invitee {
+            require!(invitee == account_id, "Voucher issued to another account");
+        }
+        require!(
+            !self.spent_voucher_nonces.contains(&(campaign.id, voucher.nonce)),
+            "Voucher already used"
+        );
+
+        let signature: [u8; 64] = signed.signature.0.try_into().expect("Invalid signature length");
+        let public_key: [u8; 32] = key.as_bytes()[1..].try_into().expect("Invalid voucher key");
+        let message = borsh::to_vec(&(env::current_account_id(), &voucher)).unwrap();
+        require!(
+            env::ed25519_verify(&signature, &message, &public_key),
+            "Invalid voucher signature"
+        );
+
+        self.spent_voucher_nonces.insert(&(campaign.id, voucher.nonce));
+        self.internal_add_invites(campaign.id, std::slice::from_ref(account_id));
+        emit_invite_voucher_redeemed(campaign.id, account_id, voucher.nonce);
+    }
+
+    fn internal_add_invites(&mut self, campaign_id: u64, account_ids: &[AccountId]) {
+        let mut invites = self
+            .campaign_invites
+            .get(&campaign_id)
+            .unwrap_or_else(|| UnorderedSet::new(StorageKey::CampaignInvitees { campaign_id }));
+        for account_id in account_ids {
+            invites.insert(account_id);
+        }
+        self.campaign_invites.insert(&campaign_id, &invites);
+    }
+
+    // Views only: contract state is public, so this hides details from
+    // frontends and indexers rather than providing confidentiality
+    pub(crate) fn can_view_submission(
+        &self,
+        campaign: &Campaign,
+        submission: &Submission,
+        viewer: Option<&AccountId>,
+    ) -> bool {
+        if campaign.campaign_type == CampaignType::Public {
+            return true;
+        }
+        match viewer {
+            Some(viewer) => viewer == &submission.submitter || self.has_any_campaign_role(campaign, viewer),
+            None => false,
+        }
+    }
+
+    pub(crate) fn redact_submission(&self, submission: Submission, viewer: Option<&AccountId>) -> Submission {
+        let campaign = self.campaigns.get(&submission.campaign_id).expect("Campaign not found");
+        if self.can_view_submission(&campaign, &submission, viewer) {
+            return submission;
+        }
+        Submission {
+            title: String::new(),
+            description: String::new(),
+            poc_link: String::new(),
+            review_comments: None,
+            ..submission
+        }
+    }
+}
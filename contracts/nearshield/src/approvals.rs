@@ -0,0 +1,169 @@
+use near_sdk::{env, require, AccountId};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use crate::*;
+
+// Payouts above the amount threshold of any of their tokens need `threshold`
+// distinct approvers. Tokens without an amount threshold always need approval.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ApprovalPolicy {
+    pub approvers: Vec<AccountId>,
+    pub threshold: u8,
+    pub amount_thresholds: Vec<TokenAmount>,
+    pub approval_ttl_ms: u64, // approvals older than this no longer count
+}
+
+// Change to an existing policy, applicable after the campaign's notice period
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PendingPolicyChange {
+    pub policy: Option<ApprovalPolicy>,
+    pub requested_at: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PayoutApproval {
+    pub approver: AccountId,
+    pub approved_at: u64,
+}
+
+#[near_bindgen]
+impl Contract {
+    // A first policy applies at once. Changing or removing an existing one
+    // (None) only announces it: a single owner key must not be able to drop
+    // the approvers and pay out in the same block. Returns when the change
+    // can be applied with `apply_approval_policy`.
+    pub fn set_approval_policy(&mut self, campaign_id: u64, policy: Option<ApprovalPolicy>) -> Option<u64> {
+        self.assert_campaign_owner(campaign_id);
+        require!(!self.paused, "Contract paused");
+        if let Some(policy) = &policy {
+            validate_approval_policy(policy);
+        }
+
+        let mut campaign = self.campaigns.get(&campaign_id).expect("Campaign not found");
+        if campaign.approval_policy.is_none() {
+            campaign.approval_policy = policy;
+            campaign.pending_approval_policy = None;
+            self.campaigns.insert(&campaign_id, &campaign);
+            emit_approval_policy_changed(campaign_id, campaign.approval_policy.as_ref());
+            return None;
+        }
+
+        let now = env::block_timestamp_ms();
+        let executable_at = now.saturating_add(campaign.cancellation_notice_ms);
+        emit_approval_policy_change_requested(campaign_id, policy.as_ref(), executable_at);
+        campaign.pending_approval_policy = Some(PendingPolicyChange { policy, requested_at: now });
+        self.campaigns.insert(&campaign_id, &campaign);
+        Some(executable_at)
+    }
+
+    // Payouts already pending approval keep waiting under the new policy
+    pub fn apply_approval_policy(&mut self, campaign_id: u64) {
+        self.assert_campaign_owner(campaign_id);
+        require!(!self.paused, "Contract paused");
+
+        let mut campaign = self.campaigns.get(&campaign_id).expect("Campaign not found");
+        let pending = campaign.pending_approval_policy.take().expect("No policy change requested");
+        require!(
+            env::block_timestamp_ms() >= pending.requested_at.saturating_add(campaign.cancellation_notice_ms),
+            "Policy notice period not over"
+        );
+        campaign.approval_policy = pending.policy;
+        self.campaigns.insert(&campaign_id, &campaign);
+        emit_approval_policy_changed(campaign_id, campaign.approval_policy.as_ref());
+    }
+
+    // Executes the payout once enough unexpired approvals are collected
+    pub fn approve_payout(&mut self, submission_id: u64) {
+        require!(!self.paused, "Contract paused");
+        let submission = self.submissions.get(&submission_id).expect("Submission not found");
+        require!(
+            submission.status == SubmissionStatus::PendingApproval,
+            "Submission is not pending approval"
+        );
+        let campaign = self.internal_get_campaign(submission.campaign_id);
+        let policy = campaign.approval_policy.clone().expect("Campaign has no approval policy");
+        let approver = env::predecessor_account_id();
+        require!(policy.approvers.contains(&approver), "Not an approver for this campaign");
+
+        let now = env::block_timestamp_ms();
+        let mut approvals = self.live_payout_approvals(submission_id, &policy, now);
+        approvals.retain(|a| a.approver != approver);
+        approvals.push(PayoutApproval { approver: approver.clone(), approved_at: now });
+        emit_payout_approved(submission_id, &approver, approvals.len(), policy.threshold);
+
+        if approvals.len() >= policy.threshold as usize {
+            self.payout_approvals.remove(&submission_id);
+            self.internal_pay_reserved(campaign, submission);
+        } else {
+            self.payout_approvals.insert(&submission_id, &approvals);
+        }
+    }
+
+    pub fn revoke_payout_approval(&mut self, submission_id: u64) {
+        require!(!self.paused, "Contract paused");
+        let submission = self.submissions.get(&submission_id).expect("Submission not found");
+        require!(
+            submission.status == SubmissionStatus::PendingApproval,
+            "Submission is not pending approval"
+        );
+        let approver = env::predecessor_account_id();
+        let mut approvals = self.payout_approvals.get(&submission_id).unwrap_or_default();
+        let before = approvals.len();
+        approvals.retain(|a| a.approver != approver);
+        require!(approvals.len() < before, "No approval to revoke");
+
+        self.payout_approvals.insert(&submission_id, &approvals);
+        emit_payout_approval_revoked(submission_id, &approver);
+    }
+
+    pub fn get_payout_approvals(&self, submission_id: u64) -> Vec<PayoutApproval> {
+        self.payout_approvals.get(&submission_id).unwrap_or_default()
+    }
+}
+
+impl Contract {
+    // Called once a payer has authorized an accepted submission whose reward is reserved
+    pub(crate) fn internal_pay_or_hold_for_approval(&mut self, campaign: Campaign, mut submission: Submission) {
+        let needs_approval = campaign.approval_policy.as_ref().map_or(false, |policy| {
+            submission
+                .rewards
+                .iter()
+                .any(|reward| reward.amount.0 > amount_of(&policy.amount_thresholds, &reward.token))
+        });
+        if !needs_approval {
+            self.internal_pay_reserved(campaign, submission);
+            return;
+        }
+
+        submission.status = SubmissionStatus::PendingApproval;
+        self.submissions.insert(&submission.id, &submission);
+        self.campaigns.insert(&campaign.id, &campaign);
+        emit_payout_approval_requested(campaign.id, submission.id, &submission.rewards);
+    }
+
+    fn live_payout_approvals(&self, submission_id: u64, policy: &ApprovalPolicy, now: u64) -> Vec<PayoutApproval> {
+        let mut approvals = self.payout_approvals.get(&submission_id).unwrap_or_default();
+        // Approvers removed from the policy and stale approvals no longer count
+        approvals.retain(|a| {
+            policy.approvers.contains(&a.approver)
+                && now < a.approved_at.saturating_add(policy.approval_ttl_ms)
+        });
+        approvals
+    }
+}
+
+fn validate_approval_policy(policy: &ApprovalPolicy) {
+    require!(policy.threshold > 0, "Threshold must be positive");
+    require!(
+        policy.threshold as usize <= policy.approvers.len(),
+        "Threshold exceeds number of approvers"
+    );
+    require!(policy.approval_ttl_ms > 0, "Approval TTL must be positive");
+    let mut approvers = policy.approvers.clone();
+    approvers.sort();
+    approvers.dedup();
+    require!(approvers.len() == policy.approvers.len(), "Duplicate approver");
+}
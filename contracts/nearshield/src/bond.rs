@@ -0,0 +1,141 @@
+use near_sdk::{env, require, AccountId, Balance, Promise};
+use near_sdk::json_types::U128;
+use crate::*;
+
+// Researcher bonds: a campaign may ask for a bond per submission to deter
+// spam. Bonds are posted per campaign, locked on submission, returned on any
+// decision except Rejected, and forfeited to the campaign pool on rejection.
+#[near_bindgen]
+impl Contract {
+    // None stops requiring a bond; submissions already made keep theirs locked
+    pub fn set_submission_bond(&mut self, campaign_id: u64, bond: Option<TokenAmount>) {
+        self.assert_campaign_owner(campaign_id);
+        require!(!self.paused, "Contract paused");
+
+        let mut campaign = self.campaigns.get(&campaign_id).expect("Campaign not found");
+        if let Some(bond) = &bond {
+            require!(bond.amount.0 > 0, "Bond must be positive");
+            // Forfeited bonds are added to the pool of the same token
+            require!(campaign.pool(&bond.token).is_some(), "Token not in campaign pool");
+        }
+        campaign.submission_bond = bond;
+        self.campaigns.insert(&campaign_id, &campaign);
+        emit_submission_bond_set(campaign_id, campaign.submission_bond.as_ref());
+    }
+
+    #[payable]
+    pub fn post_researcher_bond(&mut self, campaign_id: u64) {
+        require!(!self.paused, "Contract paused");
+        let deposit = env::attached_deposit();
+        require!(deposit > 0, "Attach at least 1 yoctoNEAR");
+
+        self.internal_post_researcher_bond(campaign_id, env::predecessor_account_id(), Token::Near, deposit);
+    }
+
+    // Only the unlocked part of the bond can be withdrawn
+    pub fn withdraw_researcher_bond(&mut self, campaign_id: u64, token: Token, amount: U128) -> Promise {
+        require!(!self.paused, "Contract paused");
+        let amount = amount.0;
+        require!(amount > 0, "Amount must be positive");
+        let account_id = env::predecessor_account_id();
+        let key = (campaign_id, account_id.clone());
+        let mut bonds = self.researcher_bonds.get(&key).unwrap_or_default();
+        require!(amount <= amount_of(&bonds, &token), "Amount exceeds posted bond");
+
+        sub_amount(&mut bonds, &token, amount);
+        self.researcher_bonds.insert(&key, &bonds);
+        emit_researcher_bond_withdrawn(campaign_id, &account_id, &token, amount);
+        self.internal_refund(&token, account_id, amount)
+    }
+
+    // A campaign that closed without deciding the submission returns its bond
+    pub fn reclaim_submission_bond(&mut self, submission_id: u64) {
+        require!(!self.paused, "Contract paused");
+        let mut submission = self.submissions.get(&submission_id).expect("Submission not found");
+        require!(env::predecessor_account_id() == submission.submitter, "Only the submitter");
+        let campaign = self.internal_get_campaign(submission.campaign_id);
+        campaign.assert_status(&[CampaignStatus::Cancelled, CampaignStatus::Finalized]);
+        let bond = submission.bond.take().expect("No bond locked");
+
+        self.internal_credit_researcher_bond(submission.campaign_id, &submission.submitter, &bond);
+        self.submissions.insert(&submission_id, &submission);
+    }
+
+    pub fn get_researcher_bond(&self, campaign_id: u64, account_id: AccountId) -> Vec<TokenAmount> {
+        self.researcher_bonds
+            .get(&(campaign_id, account_id))
+            .unwrap_or_default()
+    }
+}
+
+impl Contract {
+    // Internal: used by both NEAR and FT / MT bond deposits
+    pub(crate) fn internal_post_researcher_bond(
+        &mut self,
+        campaign_id: u64,
+        account_id: AccountId,
+        token: Token,
+        amount: Balance,
+    ) {
+        let campaign = self.internal_get_campaign(campaign_id);
+        require!(
+            !matches!(campaign.status, CampaignStatus::Cancelled | CampaignStatus::Finalized),
+            format!("Campaign is {:?}", campaign.status)
+        );
+
+        let bond = TokenAmount { token, amount: U128(amount) };
+        self.internal_credit_researcher_bond(campaign_id, &account_id, &bond);
+        emit_researcher_bond_posted(campaign_id, &account_id, &bond.token, amount);
+    }
+
+    // Moves the campaign's required bond from the researcher's balance into the submission
+    pub(crate) fn internal_lock_submission_bond(&mut self, campaign: &Campaign, submission: &mut Submission) {
+        let required = match &campaign.submission_bond {
+            Some(required) => required.clone(),
+            None => return,
+        };
+        let key = (campaign.id, submission.submitter.clone());
+        let mut bonds = self.researcher_bonds.get(&key).unwrap_or_default();
+        require!(
+            amount_of(&bonds, &required.token) >= required.amount.0,
+            "Insufficient researcher bond"
+        );
+        sub_amount(&mut bonds, &required.token, required.amount.0);
+        self.researcher_bonds.insert(&key, &bonds);
+        submission.bond = Some(required);
+    }
+
+    // Called on every review decision other than UnderReview
+    pub(crate) fn internal_settle_submission_bond(
+        &mut self,
+        campaign: &mut Campaign,
+        submission: &mut Submission,
+        forfeit: bool,
+    ) {
+        let bond = match submission.bond.take() {
+            Some(bond) => bond,
+            None => return,
+        };
+        if !forfeit {
+            self.internal_credit_researcher_bond(campaign.id, &submission.submitter, &bond);
+            return;
+        }
+
+        emit_submission_bond_forfeited(campaign.id, submission.id, &bond);
+        if campaign.status == CampaignStatus::Cancelled {
+            // The pool has already been refunded
+            self.internal_refund_contributors(campaign, &bond.token, bond.amount.0);
+        } else {
+            let pool = campaign.pool_mut(&bond.token);
+            pool.total.0 += bond.amount.0;
+            pool.remaining.0 += bond.amount.0;
+        }
+    }
+
+    fn internal_credit_researcher_bond(&mut self, campaign_id: u64, account_id: &AccountId, bond: &TokenAmount) {
+        let key = (campaign_id, account_id.clone());
+        let mut bonds = self.researcher_bonds.get(&key).unwrap_or_default();
+        add_amount(&mut bonds, &bond.token, bond.amount.0);
+        self.researcher_bonds.insert(&key, &bonds);
+    }
+}
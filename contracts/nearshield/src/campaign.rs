@@ -1,31 +1,113 @@
-use near_sdk::{env, AccountId, Balance, require, log};
+use near_sdk::{env, AccountId, Balance, PublicKey, require, log};
+use near_sdk::json_types::U128;
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
 use crate::*;
 
-#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+// Time after `end_time` during which pending submissions can still be reviewed
+pub const DEFAULT_REVIEW_GRACE_PERIOD_MS: u64 = 14 * 24 * 60 * 60 * 1000; // 14 days
+pub const MAX_REVIEW_GRACE_PERIOD_MS: u64 = 365 * 24 * 60 * 60 * 1000; // 1 year
+// Time between a cancellation request and the refund, for reviewing open reports
+pub const DEFAULT_CANCELLATION_NOTICE_MS: u64 = 7 * 24 * 60 * 60 * 1000; // 7 days
+pub const MIN_CANCELLATION_NOTICE_MS: u64 = 24 * 60 * 60 * 1000; // 1 day
+pub const MAX_CANCELLATION_NOTICE_MS: u64 = 365 * 24 * 60 * 60 * 1000; // 1 year
+// Submissions checked per `execute_cancel_campaign` call unless the caller sets a limit
+pub const DEFAULT_ESCALATION_BATCH: u64 = 50;
+// Public campaigns checked per `get_campaigns` call
+pub const MAX_CAMPAIGN_SCAN: u64 = 200;
+
+// The campaign is still running: it takes funds and the owner may withdraw or edit it
+pub const OPEN_STATUSES: [CampaignStatus; 4] = [
+    CampaignStatus::Scheduled,
+    CampaignStatus::Active,
+    CampaignStatus::Paused,
+    CampaignStatus::Exhausted,
+];
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
 pub struct Campaign {
     pub id: u64,
     pub owner: AccountId,
-    pub token: Option<AccountId>, // None = NEAR, Some = NEP-141
-    pub total_pool: Balance,
-    pub remaining_pool: Balance,
+    pub pending_owner: Option<AccountId>, // proposed, not yet accepted
+    pub pools: Vec<TokenPool>, // one per funded token, the creation token first
+    pub bugs_fixed: u32,
     pub severity_levels: Vec<SeverityConfig>,
     pub platform_fee_percent: u8, // e.g. 1%
     pub campaign_type: CampaignType,
+    pub voucher_key: Option<PublicKey>, // ed25519 key signing private campaign invites
+    pub eligibility: Option<EligibilityRules>,
     pub metadata: CampaignMetadata,
+    pub metadata_revision: u32, // current entry in the metadata history
     pub start_time: u64,
     pub end_time: Option<u64>,
-    pub cancelled: bool,
+    pub review_grace_period_ms: u64,
+    pub cancellation_notice_ms: u64,
+    pub cancel_requested_at: Option<u64>,
+    pub escalation_cursor: u64, // submissions already checked by `execute_cancel_campaign`
+    pub pending_reports: u64, // submissions not triaged yet, which hold no reservation
+    pub pending_withdrawal: Option<PendingWithdrawal>,
+    pub approval_policy: Option<ApprovalPolicy>,
+    pub pending_approval_policy: Option<PendingPolicyChange>,
+    pub submission_bond: Option<TokenAmount>, // researcher bond locked per submission
+    pub matching_left: Vec<TokenAmount>, // unused matching sponsor deposits per token
+    pub payout_mode: PayoutMode,
+    pub storage_allowance: U128, // NEAR for registering payout receivers with NEP-141 tokens
+    pub status: CampaignStatus,
+}
+
+// Owner withdrawal announced ahead of time; executable after the cancellation notice period
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PendingWithdrawal {
+    pub token: Token,
+    pub amount: U128,
+    pub requested_at: u64,
 }
 
-#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+// Campaign lifecycle. Scheduled/Active/Expired/Exhausted follow from time and
+// pool balance; Paused, Cancelling, Cancelled and Finalized are set explicitly.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum CampaignStatus {
+    Scheduled,
+    Active,
+    Paused,
+    Expired,   // past end_time, pending submissions may still be reviewed
+    Exhausted, // every token pool is empty, reopens on top-up
+    Cancelling, // notice period running, no new submissions
+    Cancelled,
+    Finalized,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
 pub struct SeverityConfig {
     pub id: u8,
     pub name: String,
-    pub max_reward_pct: u8, // % of remaining pool (capped at config)
+    pub max_reward_pct: u8, // % of each token's unreserved pool
+    pub fixed_rewards: Vec<FixedReward>, // overrides max_reward_pct for these tokens
+}
+
+impl SeverityConfig {
+    pub fn fixed_reward(&self, token: &Token) -> Option<&FixedReward> {
+        self.fixed_rewards.iter().find(|f| &f.token == token)
+    }
+
+    // (min, max) reward payable right now in `token` for this severity
+    pub fn reward_range(&self, token: &Token, unreserved: Balance) -> (Balance, Balance) {
+        match self.fixed_reward(token) {
+            Some(fixed) => (
+                fixed.min_reward.0.min(unreserved),
+                fixed.max_reward.0.min(unreserved),
+            ),
+            None => (0, (unreserved * self.max_reward_pct as u128) / 100),
+        }
+    }
 }
 
-#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
 pub struct CampaignMetadata {
     pub name: String,
     pub description: String,
@@ -35,7 +117,130 @@ pub struct CampaignMetadata {
     pub contact: Option<String>,
 }
 
+// One page of `get_campaigns`
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct CampaignPage {
+    pub campaigns: Vec<Campaign>,
+    pub next_index: Option<u64>, // where the next call should start, None at the end
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MetadataRevision {
+    pub revision: u32,
+    pub metadata: CampaignMetadata,
+    pub updated_at: u64,
+}
+
+impl Campaign {
+    pub fn severity(&self, severity_id: u8) -> &SeverityConfig {
+        self.severity_levels
+            .iter()
+            .find(|s| s.id == severity_id)
+            .expect("Invalid severity claim")
+    }
+
+    pub fn pool(&self, token: &Token) -> Option<&TokenPool> {
+        self.pools.iter().find(|p| &p.token == token)
+    }
+
+    pub fn pool_mut(&mut self, token: &Token) -> &mut TokenPool {
+        self.pools
+            .iter_mut()
+            .find(|p| &p.token == token)
+            .expect("Token not in campaign pool")
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.pools.iter().all(|p| p.remaining.0 == 0)
+    }
+
+    // Most an owner may withdraw in `token`: unreserved funds above the largest fixed reward
+    pub fn withdrawable_pool(&self, token: &Token) -> Balance {
+        let floor = self
+            .severity_levels
+            .iter()
+            .filter_map(|s| s.fixed_reward(token).map(|f| f.max_reward.0))
+            .max()
+            .unwrap_or(0);
+        self.pool(token).map_or(0, |p| p.unreserved().saturating_sub(floor))
+    }
+
+    pub fn has_ended(&self, now: u64) -> bool {
+        self.end_time.map_or(false, |end| now >= end)
+    }
+
+    // Last moment reviews are accepted; None for open-ended campaigns
+    // A cancelled campaign also gives reviewers one grace period after the notice period
+    pub fn review_deadline(&self) -> Option<u64> {
+        let ended = self.end_time.map(|end| end.saturating_add(self.review_grace_period_ms));
+        let cancelled = match (&self.status, self.cancel_requested_at) {
+            (CampaignStatus::Cancelled, Some(requested_at)) => {
+                Some(
+                    requested_at
+                        .saturating_add(self.cancellation_notice_ms)
+                        .saturating_add(self.review_grace_period_ms),
+                )
+            }
+            _ => None,
+        };
+        match (ended, cancelled) {
+            (Some(ended), Some(cancelled)) => Some(ended.min(cancelled)),
+            (ended, cancelled) => ended.or(cancelled),
+        }
+    }
+
+    pub fn effective_status(&self, now: u64) -> CampaignStatus {
+        match self.status {
+            CampaignStatus::Cancelling | CampaignStatus::Cancelled | CampaignStatus::Finalized => {
+                self.status.clone()
+            }
+            CampaignStatus::Paused if !self.has_ended(now) => CampaignStatus::Paused,
+            _ => self.derived_status(now),
+        }
+    }
+
+    // Status implied by time and pool balance alone
+    fn derived_status(&self, now: u64) -> CampaignStatus {
+        if self.has_ended(now) {
+            CampaignStatus::Expired
+        } else if now < self.start_time {
+            CampaignStatus::Scheduled
+        } else if self.is_exhausted() {
+            CampaignStatus::Exhausted
+        } else {
+            CampaignStatus::Active
+        }
+    }
+
+    // Returns true if the stored status changed
+    pub fn set_status(&mut self, status: CampaignStatus) -> bool {
+        if self.status == status {
+            return false;
+        }
+        emit_campaign_status_changed(self.id, &self.status, &status);
+        self.status = status;
+        true
+    }
+
+    // Persist time/pool driven transitions that happened since the last write
+    pub fn sync_status(&mut self, now: u64) -> bool {
+        let status = self.effective_status(now);
+        self.set_status(status)
+    }
+
+    pub fn assert_status(&self, allowed: &[CampaignStatus]) {
+        require!(
+            allowed.contains(&self.status),
+            format!("Campaign is {:?}", self.status)
+        );
+    }
+}
+
+#[near_bindgen]
 impl Contract {
+    #[payable]
     pub fn create_campaign_near(
         &mut self,
         input: CreateCampaignInput,
@@ -43,52 +248,65 @@ impl Contract {
         require!(!self.paused, "Contract paused");
         let deposit = env::attached_deposit();
         require!(deposit > 0, "Attach at least 1 yoctoNEAR");
+        let storage_allowance = input.storage_allowance.map_or(0, |a| a.0);
+        require!(deposit > storage_allowance, "Deposit must exceed the storage allowance");
 
         self.internal_create_campaign(
             env::predecessor_account_id(),
-            deposit,
-            None, // NEAR
+            deposit - storage_allowance,
+            Token::Near,
             input,
         )
+        .unwrap_or_else(|reason| env::panic_str(&reason))
     }
 
-    // Internal: used by both NEAR deposit and FT deposit
+    // Internal: used by both NEAR deposit and FT / MT deposit. Invalid input is
+    // returned as Err before any state changes, so token deposits can be refunded.
     pub(crate) fn internal_create_campaign(
         &mut self,
         owner: AccountId,
         amount: Balance,
-        token: Option<AccountId>,
+        token: Token,
         input: CreateCampaignInput,
-    ) -> u64 {
-        // Validate severity configs
-        require!(!input.severity_levels.is_empty(), "At least one severity level");
-        for level in &input.severity_levels {
-            require!(level.max_reward_pct <= 100, "Max reward pct must be ≤100");
+    ) -> Result<u64, String> {
+        let now = env::block_timestamp_ms();
+        let start_time = input.start_time.unwrap_or(now);
+        validate_schedule(start_time, input.end_time, now)?;
+        validate_eligibility(&input.eligibility)?;
+        let review_grace_period_ms = input
+            .review_grace_period_ms
+            .unwrap_or(DEFAULT_REVIEW_GRACE_PERIOD_MS);
+        validate_review_grace_period(review_grace_period_ms)?;
+        let cancellation_notice_ms = input
+            .cancellation_notice_ms
+            .unwrap_or(DEFAULT_CANCELLATION_NOTICE_MS);
+        if cancellation_notice_ms < MIN_CANCELLATION_NOTICE_MS {
+            return Err("Cancellation notice period too short".to_string());
         }
+        if cancellation_notice_ms > MAX_CANCELLATION_NOTICE_MS {
+            return Err("Cancellation notice period too long".to_string());
+        }
+        let pools = vec![TokenPool {
+            total: U128(amount),
+            remaining: U128(amount),
+            ..TokenPool::new(token.clone())
+        }];
+        let severity_configs = build_severity_configs(input.severity_levels, &pools)?;
 
         let campaign_id = self.next_campaign_id;
         self.next_campaign_id += 1;
 
-        let severity_configs: Vec<SeverityConfig> = input
-            .severity_levels
-            .into_iter()
-            .enumerate()
-            .map(|(i, s)| SeverityConfig {
-                id: i as u8,
-                name: s.name,
-                max_reward_pct: s.max_reward_pct,
-            })
-            .collect();
-
-        let campaign = Campaign {
+        let mut campaign = Campaign {
             id: campaign_id,
             owner,
-            token,
-            total_pool: amount,
-            remaining_pool: amount,
+            pending_owner: None,
+            pools,
+            bugs_fixed: 0,
             severity_levels: severity_configs,
             platform_fee_percent: 1, // hardcoded 1%
             campaign_type: input.campaign_type,
+            voucher_key: None,
+            eligibility: input.eligibility,
             metadata: CampaignMetadata {
                 name: input.name,
                 description: input.description,
@@ -97,42 +315,442 @@ impl Contract {
                 rules: input.rules,
                 contact: input.contact,
             },
-            start_time: env::block_timestamp_ms(),
+            metadata_revision: 0,
+            start_time,
             end_time: input.end_time,
-            cancelled: false,
+            review_grace_period_ms,
+            cancellation_notice_ms,
+            cancel_requested_at: None,
+            escalation_cursor: 0,
+            pending_reports: 0,
+            pending_withdrawal: None,
+            approval_policy: None,
+            pending_approval_policy: None,
+            submission_bond: None,
+            matching_left: vec![],
+            payout_mode: input.payout_mode.unwrap_or(PayoutMode::Push),
+            storage_allowance: input.storage_allowance.unwrap_or(U128(0)),
+            status: CampaignStatus::Active,
         };
+        campaign.status = campaign.effective_status(now);
+        self.internal_push_metadata_revision(&mut campaign);
 
         self.campaigns.insert(&campaign_id, &campaign);
+        if campaign.campaign_type == CampaignType::Public {
+            self.public_campaigns.push(&campaign_id);
+        }
+        self.increment_project_campaigns(&campaign.owner);
+        self.record_project_deposit(&campaign.owner, &token, amount);
         emit_campaign_created(&campaign);
-        campaign_id
+        Ok(campaign_id)
+    }
+
+    #[payable]
+    pub fn top_up_campaign(&mut self, campaign_id: u64) {
+        require!(!self.paused, "Contract paused");
+        let deposit = env::attached_deposit();
+        require!(deposit > 0, "Attach at least 1 yoctoNEAR");
+
+        self.internal_top_up_campaign(
+            campaign_id,
+            env::predecessor_account_id(),
+            deposit,
+            Token::Near,
+        );
     }
 
-    pub fn cancel_campaign(&mut self, campaign_id: u64) -> Promise {
+    // Internal: used by both NEAR top-up and FT / MT top-up. A token the campaign
+    // does not hold yet opens a new pool.
+    pub(crate) fn internal_top_up_campaign(
+        &mut self,
+        campaign_id: u64,
+        sender: AccountId,
+        amount: Balance,
+        token: Token,
+    ) {
+        let mut campaign = self.internal_get_campaign(campaign_id);
+        require!(sender == campaign.owner, "Only campaign owner");
+        campaign.assert_status(&OPEN_STATUSES);
+
+        if campaign.pool(&token).is_none() {
+            campaign.pools.push(TokenPool::new(token.clone()));
+        }
+        let pool = campaign.pool_mut(&token);
+        pool.total.0 += amount;
+        pool.remaining.0 += amount;
+        campaign.sync_status(env::block_timestamp_ms());
+        self.campaigns.insert(&campaign_id, &campaign);
+
+        self.record_project_deposit(&campaign.owner, &token, amount);
+        emit_campaign_topped_up(&campaign, &sender, &token, amount);
+    }
+
+    // Immediate full refund, only before the campaign has opened
+    pub fn cancel_campaign(&mut self, campaign_id: u64) -> Vec<TokenAmount> {
         self.assert_campaign_owner(campaign_id);
         require!(!self.paused, "Contract paused");
 
-        let mut campaign = self.campaigns.get(&campaign_id).expect("Campaign not found");
-        require!(!campaign.cancelled, "Already cancelled");
+        let mut campaign = self.internal_get_campaign(campaign_id);
+        campaign.assert_status(&[CampaignStatus::Scheduled]);
+        self.internal_cancel(campaign)
+    }
+
+    // Phase one: stop new submissions and start the notice period
+    pub fn request_cancel_campaign(&mut self, campaign_id: u64) -> u64 {
+        self.assert_campaign_owner(campaign_id);
+        require!(!self.paused, "Contract paused");
+
+        let mut campaign = self.internal_get_campaign(campaign_id);
+        campaign.assert_status(&[
+            CampaignStatus::Active,
+            CampaignStatus::Paused,
+            CampaignStatus::Expired,
+            CampaignStatus::Exhausted,
+        ]);
 
-        campaign.cancelled = true;
-        let refund_amount = campaign.remaining_pool;
-        campaign.remaining_pool = 0;
+        let now = env::block_timestamp_ms();
+        let executable_at = now.saturating_add(campaign.cancellation_notice_ms);
+        campaign.cancel_requested_at = Some(now);
+        campaign.set_status(CampaignStatus::Cancelling);
         self.campaigns.insert(&campaign_id, &campaign);
 
-        emit_campaign_cancelled(campaign_id, refund_amount);
+        emit_campaign_cancel_requested(campaign_id, executable_at);
+        executable_at
+    }
+
+    // Phase two: reports still pending are escalated to UnderReview so their
+    // reservation survives the refund of everything else. Escalation runs in
+    // batches of `limit` submissions; None is returned until the last batch,
+    // which also refunds.
+    pub fn execute_cancel_campaign(&mut self, campaign_id: u64, limit: Option<u64>) -> Option<Vec<TokenAmount>> {
+        self.assert_campaign_owner(campaign_id);
+        require!(!self.paused, "Contract paused");
+
+        let mut campaign = self.internal_get_campaign(campaign_id);
+        campaign.assert_status(&[CampaignStatus::Cancelling]);
+        let requested_at = campaign.cancel_requested_at.expect("Cancellation not requested");
+        require!(
+            env::block_timestamp_ms() >= requested_at.saturating_add(campaign.cancellation_notice_ms),
+            "Cancellation notice period not over"
+        );
+
+        if !self.internal_escalate_pending(&mut campaign, limit.unwrap_or(DEFAULT_ESCALATION_BATCH)) {
+            self.campaigns.insert(&campaign_id, &campaign);
+            return None;
+        }
+        Some(self.internal_cancel(campaign))
+    }
+
+    // Returns the refunded amounts; each token is sent in its own transfer
+    fn internal_cancel(&mut self, mut campaign: Campaign) -> Vec<TokenAmount> {
+        campaign.set_status(CampaignStatus::Cancelled);
+        // Reserved funds stay until their submissions are decided
+        let mut refunds = vec![];
+        for pool in campaign.pools.iter_mut() {
+            let refund_amount = pool.unreserved();
+            pool.remaining.0 -= refund_amount;
+            add_amount(&mut refunds, &pool.token, refund_amount);
+        }
+        self.internal_refund_storage_allowance(&mut campaign);
+        // Reports decided later are not matched
+        self.internal_refund_matching(&mut campaign);
+        self.campaigns.insert(&campaign.id, &campaign);
+
+        emit_campaign_cancelled(campaign.id, &refunds);
+
+        // Refund owner and sponsors
+        for refund in &refunds {
+            self.internal_refund_contributors(&campaign, &refund.token, refund.amount.0);
+        }
+        refunds
+    }
+
+    // Announce a partial withdrawal; replaces any earlier request
+    pub fn request_pool_withdrawal(&mut self, campaign_id: u64, token: Token, amount: U128) -> u64 {
+        self.assert_campaign_owner(campaign_id);
+        require!(!self.paused, "Contract paused");
+
+        let mut campaign = self.internal_get_campaign(campaign_id);
+        campaign.assert_status(&OPEN_STATUSES);
+        require!(campaign.pending_reports == 0, "Triage pending reports before withdrawing");
+        let amount = amount.0;
+        require!(amount > 0, "Amount must be positive");
+        require!(amount <= self.owner_withdrawable(&campaign, &token), "Amount exceeds withdrawable pool");
+
+        let now = env::block_timestamp_ms();
+        campaign.pending_withdrawal = Some(PendingWithdrawal {
+            token: token.clone(),
+            amount: U128(amount),
+            requested_at: now,
+        });
+        self.campaigns.insert(&campaign_id, &campaign);
+
+        let executable_at = now.saturating_add(campaign.cancellation_notice_ms);
+        emit_pool_withdrawal_requested(campaign_id, &token, amount, executable_at);
+        executable_at
+    }
+
+    // Withdraw up to the announced amount of the announced token once the notice period is over
+    pub fn withdraw_from_pool(&mut self, campaign_id: u64, amount: U128) -> Promise {
+        self.assert_campaign_owner(campaign_id);
+        require!(!self.paused, "Contract paused");
+
+        let mut campaign = self.internal_get_campaign(campaign_id);
+        campaign.assert_status(&OPEN_STATUSES);
+        let pending = campaign.pending_withdrawal.take().expect("No withdrawal requested");
+        require!(
+            env::block_timestamp_ms() >= pending.requested_at.saturating_add(campaign.cancellation_notice_ms),
+            "Withdrawal notice period not over"
+        );
+        let amount = amount.0;
+        require!(amount > 0, "Amount must be positive");
+        require!(amount <= pending.amount.0, "Amount exceeds requested withdrawal");
+        // Reports, reservations or payouts may have happened since the request;
+        // an untriaged report holds nothing yet, so it blocks the withdrawal
+        require!(campaign.pending_reports == 0, "Triage pending reports before withdrawing");
+        let token = pending.token;
+        require!(amount <= self.owner_withdrawable(&campaign, &token), "Amount exceeds withdrawable pool");
 
-        // Refund owner
-        if let Some(token) = &campaign.token {
-            ext_ft::ext(token.clone())
-                .with_attached_deposit(1)
+        let pool = campaign.pool_mut(&token);
+        pool.total.0 -= amount;
+        pool.remaining.0 -= amount;
+        campaign.sync_status(env::block_timestamp_ms());
+        self.campaigns.insert(&campaign_id, &campaign);
+        emit_pool_withdrawn(campaign_id, &token, amount);
+
+        self.internal_refund_owner(&campaign, &token, amount).then(
+            Self::ext(env::current_account_id())
                 .with_static_gas(Gas(5 * TGAS))
-                .ft_transfer(campaign.owner, refund_amount, None)
-        } else {
-            Promise::new(campaign.owner).transfer(refund_amount)
+                .on_pool_withdrawal_resolved(campaign_id, token, amount),
+        )
+    }
+
+    #[private]
+    pub fn on_pool_withdrawal_resolved(&mut self, campaign_id: u64, token: Token, amount: Balance) -> bool {
+        if let PromiseResult::Successful(_) = env::promise_result(0) {
+            return true;
+        }
+        // Transfer failed: the funds never left, put them back in the pool
+        let mut campaign = self.campaigns.get(&campaign_id).expect("Campaign not found");
+        let pool = campaign.pool_mut(&token);
+        pool.total.0 += amount;
+        pool.remaining.0 += amount;
+        campaign.sync_status(env::block_timestamp_ms());
+        self.campaigns.insert(&campaign_id, &campaign);
+        emit_pool_withdrawal_reverted(campaign_id, &token, amount);
+        false
+    }
+
+    // Permissionless: once the review grace period is over, return leftovers to the owner and sponsors
+    pub fn finalize_campaign(&mut self, campaign_id: u64) -> Vec<TokenAmount> {
+        require!(!self.paused, "Contract paused");
+
+        let mut campaign = self.internal_get_campaign(campaign_id);
+        // Cancelled campaigns only hold reservations of undecided reports by now
+        campaign.assert_status(&[CampaignStatus::Expired, CampaignStatus::Cancelled]);
+        let deadline = campaign.review_deadline().expect("Campaign has no review deadline");
+        require!(env::block_timestamp_ms() >= deadline, "Review grace period not over");
+
+        campaign.set_status(CampaignStatus::Finalized);
+        // The review window is closed, so reservations of undecided reports
+        // lapse; accepted rewards stay reserved until they are paid
+        let mut refunds = vec![];
+        for pool in campaign.pools.iter_mut() {
+            add_amount(&mut refunds, &pool.token, pool.remaining.0 - pool.awarded.0);
+            pool.remaining = pool.awarded;
+            pool.reserved = pool.awarded;
+        }
+        self.internal_refund_storage_allowance(&mut campaign);
+        self.internal_refund_matching(&mut campaign);
+        self.campaigns.insert(&campaign_id, &campaign);
+
+        emit_campaign_finalized(campaign_id, &refunds);
+
+        for refund in &refunds {
+            self.internal_refund_contributors(&campaign, &refund.token, refund.amount.0);
+        }
+        refunds
+    }
+
+    pub fn get_reward_table(&self, campaign_id: u64) -> Vec<RewardTableEntry> {
+        let campaign = self.campaigns.get(&campaign_id).expect("Campaign not found");
+        let mut table = vec![];
+        for level in &campaign.severity_levels {
+            for pool in &campaign.pools {
+                let (min_reward, max_reward) = level.reward_range(&pool.token, pool.unreserved());
+                table.push(RewardTableEntry {
+                    severity_id: level.id,
+                    name: level.name.clone(),
+                    token: pool.token.clone(),
+                    fixed: level.fixed_reward(&pool.token).is_some(),
+                    min_reward: U128(min_reward),
+                    max_reward: U128(max_reward),
+                });
+            }
+        }
+        table
+    }
+
+    // Only before the campaign opens: reschedule or change reward configuration
+    pub fn update_scheduled_campaign(&mut self, campaign_id: u64, input: UpdateScheduledCampaignInput) {
+        self.assert_campaign_owner(campaign_id);
+        require!(!self.paused, "Contract paused");
+
+        let mut campaign = self.internal_get_campaign(campaign_id);
+        campaign.assert_status(&[CampaignStatus::Scheduled]);
+
+        let now = env::block_timestamp_ms();
+        let start_time = input.start_time.unwrap_or(campaign.start_time);
+        let end_time = input.end_time.or(campaign.end_time);
+        validate_schedule(start_time, end_time, now).unwrap_or_else(|reason| env::panic_str(&reason));
+        campaign.start_time = start_time;
+        campaign.end_time = end_time;
+
+        if let Some(levels) = input.severity_levels {
+            campaign.severity_levels =
+                build_severity_configs(levels, &campaign.pools).unwrap_or_else(|reason| env::panic_str(&reason));
+        }
+        if let Some(grace) = input.review_grace_period_ms {
+            validate_review_grace_period(grace).unwrap_or_else(|reason| env::panic_str(&reason));
+            campaign.review_grace_period_ms = grace;
+        }
+
+        campaign.sync_status(now);
+        self.campaigns.insert(&campaign_id, &campaign);
+        emit_campaign_updated(&campaign);
+    }
+
+    // Scope and rules may evolve; every prior revision stays queryable
+    pub fn update_campaign_metadata(&mut self, campaign_id: u64, input: UpdateCampaignMetadataInput) -> u32 {
+        self.assert_campaign_owner(campaign_id);
+        require!(!self.paused, "Contract paused");
+
+        let mut campaign = self.internal_get_campaign(campaign_id);
+        campaign.assert_status(&OPEN_STATUSES);
+
+        let metadata = &mut campaign.metadata;
+        if let Some(name) = input.name {
+            metadata.name = name;
+        }
+        if let Some(description) = input.description {
+            metadata.description = description;
+        }
+        if input.repo_link.is_some() {
+            metadata.repo_link = input.repo_link;
+        }
+        if input.scope.is_some() {
+            metadata.scope = input.scope;
+        }
+        if input.rules.is_some() {
+            metadata.rules = input.rules;
+        }
+        if input.contact.is_some() {
+            metadata.contact = input.contact;
+        }
+
+        self.internal_push_metadata_revision(&mut campaign);
+        self.campaigns.insert(&campaign_id, &campaign);
+        emit_campaign_metadata_updated(&campaign);
+        campaign.metadata_revision
+    }
+
+    // Step one of an ownership transfer; None withdraws the proposal
+    pub fn propose_campaign_owner(&mut self, campaign_id: u64, new_owner: Option<AccountId>) {
+        self.assert_campaign_owner(campaign_id);
+        require!(!self.paused, "Contract paused");
+
+        let mut campaign = self.internal_get_campaign(campaign_id);
+        require!(
+            !matches!(campaign.status, CampaignStatus::Cancelled | CampaignStatus::Finalized),
+            format!("Campaign is {:?}", campaign.status)
+        );
+        require!(new_owner.as_ref() != Some(&campaign.owner), "Already the owner");
+
+        campaign.pending_owner = new_owner;
+        self.campaigns.insert(&campaign_id, &campaign);
+        emit_campaign_ownership_proposed(campaign_id, &campaign.owner, campaign.pending_owner.as_ref());
+    }
+
+    // Step two: the proposed account takes over, including future refunds
+    pub fn accept_campaign_ownership(&mut self, campaign_id: u64) {
+        require!(!self.paused, "Contract paused");
+
+        let mut campaign = self.internal_get_campaign(campaign_id);
+        let new_owner = env::predecessor_account_id();
+        require!(
+            campaign.pending_owner.as_ref() == Some(&new_owner),
+            "Only the proposed owner"
+        );
+        require!(
+            !matches!(campaign.status, CampaignStatus::Cancelled | CampaignStatus::Finalized),
+            format!("Campaign is {:?}", campaign.status)
+        );
+
+        let old_owner = std::mem::replace(&mut campaign.owner, new_owner);
+        campaign.pending_owner = None;
+        // A withdrawal announced by the previous owner does not carry over
+        campaign.pending_withdrawal = None;
+        self.campaigns.insert(&campaign_id, &campaign);
+
+        self.transfer_project_stats(&old_owner, &campaign.owner, &campaign);
+        emit_campaign_ownership_transferred(campaign_id, &old_owner, &campaign.owner);
+    }
+
+    // Stops new submissions; reviews and top-ups remain possible
+    pub fn pause_campaign(&mut self, campaign_id: u64) {
+        self.assert_campaign_owner(campaign_id);
+        require!(!self.paused, "Contract paused");
+
+        let mut campaign = self.internal_get_campaign(campaign_id);
+        campaign.assert_status(&[
+            CampaignStatus::Scheduled,
+            CampaignStatus::Active,
+            CampaignStatus::Exhausted,
+        ]);
+        campaign.set_status(CampaignStatus::Paused);
+        self.campaigns.insert(&campaign_id, &campaign);
+    }
+
+    pub fn resume_campaign(&mut self, campaign_id: u64) {
+        self.assert_campaign_owner(campaign_id);
+        require!(!self.paused, "Contract paused");
+
+        let mut campaign = self.internal_get_campaign(campaign_id);
+        campaign.assert_status(&[CampaignStatus::Paused]);
+        let status = campaign.derived_status(env::block_timestamp_ms());
+        campaign.set_status(status);
+        self.campaigns.insert(&campaign_id, &campaign);
+    }
+
+    // Loads a campaign, persisting any status transition that is due
+    pub(crate) fn internal_get_campaign(&mut self, campaign_id: u64) -> Campaign {
+        let mut campaign = self.campaigns.get(&campaign_id).expect("Campaign not found");
+        if campaign.sync_status(env::block_timestamp_ms()) {
+            self.campaigns.insert(&campaign_id, &campaign);
         }
+        campaign
     }
 
-    fn assert_campaign_owner(&self, campaign_id: u64) {
+    fn internal_push_metadata_revision(&mut self, campaign: &mut Campaign) {
+        let campaign_id = campaign.id;
+        let mut history = self
+            .campaign_metadata_history
+            .get(&campaign_id)
+            .unwrap_or_else(|| Vector::new(StorageKey::CampaignMetadataRevisions { campaign_id }));
+        campaign.metadata_revision += 1;
+        history.push(&MetadataRevision {
+            revision: campaign.metadata_revision,
+            metadata: campaign.metadata.clone(),
+            updated_at: env::block_timestamp_ms(),
+        });
+        self.campaign_metadata_history.insert(&campaign_id, &history);
+    }
+
+    pub(crate) fn internal_refund_owner(&self, campaign: &Campaign, token: &Token, amount: Balance) -> Promise {
+        self.internal_transfer(token, campaign.owner.clone(), amount)
+    }
+
+    pub(crate) fn assert_campaign_owner(&self, campaign_id: u64) {
         let campaign = self.campaigns.get(&campaign_id).expect("Campaign not found");
         require!(
             env::predecessor_account_id() == campaign.owner,
@@ -140,3 +758,66 @@ impl Contract {
         );
     }
 }
+
+// Validation helpers return Err instead of panicking so token deposits can be refunded
+fn validate_schedule(start_time: u64, end_time: Option<u64>, now: u64) -> Result<(), String> {
+    if start_time < now {
+        return Err("Start time must not be in the past".to_string());
+    }
+    if end_time.map_or(false, |end_time| end_time <= start_time) {
+        return Err("End time must be after start time".to_string());
+    }
+    Ok(())
+}
+
+fn validate_review_grace_period(grace_ms: u64) -> Result<(), String> {
+    if grace_ms > MAX_REVIEW_GRACE_PERIOD_MS {
+        return Err("Review grace period too long".to_string());
+    }
+    Ok(())
+}
+
+fn validate_eligibility(rules: &Option<EligibilityRules>) -> Result<(), String> {
+    if rules.as_ref().and_then(|r| r.max_rejection_ratio_pct).map_or(false, |pct| pct > 100) {
+        return Err("Max rejection ratio pct must be ≤100".to_string());
+    }
+    Ok(())
+}
+
+fn build_severity_configs(levels: Vec<SeverityConfigInput>, pools: &[TokenPool]) -> Result<Vec<SeverityConfig>, String> {
+    if levels.is_empty() {
+        return Err("At least one severity level".to_string());
+    }
+    for level in &levels {
+        if level.max_reward_pct > 100 {
+            return Err("Max reward pct must be ≤100".to_string());
+        }
+        for (i, fixed) in level.fixed_rewards.iter().enumerate() {
+            if level.fixed_rewards[..i].iter().any(|f| f.token == fixed.token) {
+                return Err("Duplicate fixed reward token".to_string());
+            }
+            if fixed.max_reward.0 == 0 {
+                return Err("Max reward must be positive".to_string());
+            }
+            if fixed.min_reward.0 > fixed.max_reward.0 {
+                return Err("Min reward exceeds max reward".to_string());
+            }
+            // Tokens not funded yet are capped by their pool once topped up
+            if let Some(pool) = pools.iter().find(|p| p.token == fixed.token) {
+                if fixed.max_reward.0 > pool.remaining.0 {
+                    return Err("Max reward exceeds campaign pool".to_string());
+                }
+            }
+        }
+    }
+    Ok(levels
+        .into_iter()
+        .enumerate()
+        .map(|(i, s)| SeverityConfig {
+            id: i as u8,
+            name: s.name,
+            max_reward_pct: s.max_reward_pct,
+            fixed_rewards: s.fixed_rewards,
+        })
+        .collect())
+}
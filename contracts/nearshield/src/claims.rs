@@ -0,0 +1,75 @@
+use near_sdk::{env, require, AccountId, Balance, Promise};
+use crate::*;
+
+// Pull payouts: rewards of Pull-mode campaigns and refunds that could not be
+// delivered are credited per (account, token) and withdrawn by the account
+#[near_bindgen]
+impl Contract {
+    // Applies to payouts made from now on; rewards already sent or credited are unaffected
+    pub fn set_payout_mode(&mut self, campaign_id: u64, mode: PayoutMode) {
+        self.assert_campaign_owner(campaign_id);
+        require!(!self.paused, "Contract paused");
+
+        let mut campaign = self.campaigns.get(&campaign_id).expect("Campaign not found");
+        campaign.payout_mode = mode;
+        self.campaigns.insert(&campaign_id, &campaign);
+        emit_payout_mode_changed(campaign_id, &campaign.payout_mode);
+    }
+
+    pub fn claim_rewards(&mut self, token: Token) -> Promise {
+        require!(!self.paused, "Contract paused");
+        let account_id = env::predecessor_account_id();
+        let mut claimable = self.claimable.get(&account_id).unwrap_or_default();
+        let amount = amount_of(&claimable, &token);
+        require!(amount > 0, "Nothing to claim in this token");
+
+        sub_amount(&mut claimable, &token, amount);
+        self.claimable.insert(&account_id, &claimable);
+        self.internal_send_claim(account_id, token, amount)
+    }
+
+    // One transfer per token; each is re-credited separately if it fails
+    pub fn claim_all(&mut self) -> Vec<TokenAmount> {
+        require!(!self.paused, "Contract paused");
+        let account_id = env::predecessor_account_id();
+        let claimed = self.claimable.remove(&account_id).unwrap_or_default();
+        require!(!claimed.is_empty(), "Nothing to claim");
+
+        for claim in &claimed {
+            self.internal_send_claim(account_id.clone(), claim.token.clone(), claim.amount.0);
+        }
+        claimed
+    }
+
+    pub fn get_claimable(&self, account_id: AccountId) -> Vec<TokenAmount> {
+        self.claimable.get(&account_id).unwrap_or_default()
+    }
+
+    #[private]
+    pub fn on_claim_resolved(&mut self, account_id: AccountId, token: Token, amount: Balance) -> bool {
+        if let PromiseResult::Successful(_) = env::promise_result(0) {
+            return true;
+        }
+        // The funds never left; they stay claimable
+        self.internal_credit_claimable(&account_id, &token, amount);
+        emit_claim_failed(&account_id, &token, amount);
+        false
+    }
+}
+
+impl Contract {
+    pub(crate) fn internal_credit_claimable(&mut self, account_id: &AccountId, token: &Token, amount: Balance) {
+        let mut claimable = self.claimable.get(account_id).unwrap_or_default();
+        add_amount(&mut claimable, token, amount);
+        self.claimable.insert(account_id, &claimable);
+    }
+
+    fn internal_send_claim(&self, account_id: AccountId, token: Token, amount: Balance) -> Promise {
+        emit_rewards_claimed(&account_id, &token, amount);
+        self.internal_transfer(&token, account_id.clone(), amount).then(
+            Self::ext(env::current_account_id())
+                .with_static_gas(Gas(5 * TGAS))
+                .on_claim_resolved(account_id, token, amount),
+        )
+    }
+}
@@ -0,0 +1,117 @@
+use near_sdk::{env, require, AccountId};
+use near_sdk::json_types::{Base64VecU8, U128, U64};
+use near_sdk::serde::{Deserialize, Serialize};
+use crate::*;
+
+// A DAO-owned campaign is one whose owner is a Sputnik-style DAO contract: every
+// owner action arrives as a FunctionCall proposal executed by the DAO.
+pub const MAX_REVIEW_BATCH: usize = 5;
+const REVIEW_BATCH_BASE_TGAS: u64 = 20;
+const REVIEW_TGAS: u64 = 5; // state changes of one review
+// Gas prepaid to the promises of one token payout, see `internal_send_payout`:
+// NEP-141 checks storage (5 + 5) in `on_payout_storage_checked` (60), which
+// covers registration and the transfer; the others transfer and resolve (15)
+const FT_PAYOUT_TGAS: u64 = 5 + 5 + 60;
+const MT_PAYOUT_TGAS: u64 = 5 + 15;
+const NEAR_PAYOUT_TGAS: u64 = 15;
+// Owner refund with its callback, per pool token, when a cancelled campaign releases a reservation
+const REFUND_TGAS: u64 = 5 + 5;
+// Leaves room for the DAO's own `act_proposal` within the 300 TGas limit
+const MAX_REVIEW_PROPOSAL_TGAS: u64 = 250;
+
+// Arguments of one review, identical to `review_submission`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SubmissionReview {
+    pub submission_id: u64,
+    pub status: SubmissionStatus,
+    pub rewards: Option<Vec<TokenAmount>>,
+    pub comments: Option<String>,
+}
+
+// Sputnik v2 `ProposalKind::FunctionCall`, ready to pass as `proposal.kind` to `add_proposal`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum DaoProposalKind {
+    FunctionCall {
+        receiver_id: AccountId,
+        actions: Vec<DaoActionCall>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DaoActionCall {
+    pub method_name: String,
+    pub args: Base64VecU8, // JSON-encoded method arguments
+    pub deposit: U128,
+    pub gas: U64,
+}
+
+#[near_bindgen]
+impl Contract {
+    // Settle several submissions in one call (one DAO proposal). All-or-nothing:
+    // any invalid review reverts the whole batch.
+    #[payable]
+    pub fn review_submissions_batch(&mut self, reviews: Vec<SubmissionReview>) {
+        require!(!self.paused, "Contract paused");
+        require!(!reviews.is_empty(), "Empty review batch");
+        require!(reviews.len() <= MAX_REVIEW_BATCH, "Review batch too large");
+
+        let actor = env::predecessor_account_id();
+        for review in reviews {
+            self.internal_review_submission(
+                actor.clone(),
+                review.submission_id,
+                review.status,
+                review.rewards,
+                review.comments,
+            );
+        }
+    }
+
+    // Builds the proposal kind a DAO needs to run `review_submissions_batch`
+    pub fn get_review_proposal(&self, reviews: Vec<SubmissionReview>) -> DaoProposalKind {
+        require!(!reviews.is_empty(), "Empty review batch");
+        require!(reviews.len() <= MAX_REVIEW_BATCH, "Review batch too large");
+
+        let gas_tgas = REVIEW_BATCH_BASE_TGAS + reviews.iter().map(|r| self.review_gas_tgas(r)).sum::<u64>();
+        require!(gas_tgas <= MAX_REVIEW_PROPOSAL_TGAS, "Review batch needs too much gas, split it");
+        let args = serde_json::to_vec(&serde_json::json!({ "reviews": reviews })).unwrap();
+        DaoProposalKind::FunctionCall {
+            receiver_id: env::current_account_id(),
+            actions: vec![DaoActionCall {
+                method_name: "review_submissions_batch".to_string(),
+                args: Base64VecU8(args),
+                deposit: U128(0),
+                gas: U64(gas_tgas * TGAS),
+            }],
+        }
+    }
+}
+
+impl Contract {
+    // Upper bound for one review: every reward token may pay out, and a
+    // release on a cancelled campaign refunds the owner in every pool token
+    fn review_gas_tgas(&self, review: &SubmissionReview) -> u64 {
+        let payouts: u64 = match review.status {
+            SubmissionStatus::Accepted => review
+                .rewards
+                .iter()
+                .flatten()
+                .map(|reward| match reward.token {
+                    Token::Near => NEAR_PAYOUT_TGAS,
+                    Token::Ft(_) => FT_PAYOUT_TGAS,
+                    Token::Mt { .. } => MT_PAYOUT_TGAS,
+                })
+                .sum(),
+            _ => 0,
+        };
+        let refunds = self
+            .submissions
+            .get(&review.submission_id)
+            .and_then(|submission| self.campaigns.get(&submission.campaign_id))
+            .map_or(0, |campaign| REFUND_TGAS * campaign.pools.len() as u64);
+        REVIEW_TGAS + payouts + refunds
+    }
+}
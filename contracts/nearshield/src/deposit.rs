@@ -1,4 +1,5 @@
 use near_sdk::ext_contract;
+use near_contract_standards::storage_management::{StorageBalance, StorageBalanceBounds};
 
 // Interface for NEP-141 FT contract
 #[ext_contract(ext_ft)]
@@ -6,3 +7,24 @@ trait FungibleToken {
     fn ft_transfer(&mut self, receiver_id: AccountId, amount: U128, memo: Option<String>);
     fn ft_balance_of(&self, account_id: AccountId) -> U128;
 }
+
+// Interface for NEP-245 multi-token contracts
+#[ext_contract(ext_mt)]
+trait MultiToken {
+    fn mt_transfer(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: String,
+        amount: U128,
+        approval: Option<(AccountId, u64)>,
+        memo: Option<String>,
+    );
+}
+
+// NEP-145 storage management of NEP-141 token contracts
+#[ext_contract(ext_storage)]
+trait StorageManagement {
+    fn storage_deposit(&mut self, account_id: Option<AccountId>, registration_only: Option<bool>) -> StorageBalance;
+    fn storage_balance_of(&self, account_id: AccountId) -> Option<StorageBalance>;
+    fn storage_balance_bounds(&self) -> StorageBalanceBounds;
+}
@@ -1,6 +1,14 @@
 use near_sdk::{env, log};
 use crate::*;
 
+// Balances are rendered as strings, like every other amount in the events
+fn token_amounts_json(amounts: &[TokenAmount]) -> Vec<serde_json::Value> {
+    amounts
+        .iter()
+        .map(|a| serde_json::json!({ "token": a.token, "amount": a.amount.0.to_string() }))
+        .collect()
+}
+
 pub fn emit_campaign_created(campaign: &Campaign) {
     log!(format!(
         "EVENT_JSON:{}",
@@ -11,14 +19,69 @@ pub fn emit_campaign_created(campaign: &Campaign) {
             "data": {
                 "campaign_id": campaign.id,
                 "owner": campaign.owner,
-                "total_pool": campaign.total_pool.to_string(),
-                "token": campaign.token,
+                "pools": campaign
+                    .pools
+                    .iter()
+                    .map(|p| serde_json::json!({ "token": p.token, "total": p.total.0.to_string() }))
+                    .collect::<Vec<_>>(),
+                "name": campaign.metadata.name,
+            }
+        })
+    ));
+}
+
+pub fn emit_campaign_updated(campaign: &Campaign) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "campaign_updated",
+            "data": {
+                "campaign_id": campaign.id,
+                "start_time": campaign.start_time,
+                "end_time": campaign.end_time,
+            }
+        })
+    ));
+}
+
+pub fn emit_campaign_metadata_updated(campaign: &Campaign) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "campaign_metadata_updated",
+            "data": {
+                "campaign_id": campaign.id,
+                "revision": campaign.metadata_revision,
                 "name": campaign.metadata.name,
             }
         })
     ));
 }
 
+pub fn emit_campaign_topped_up(campaign: &Campaign, sender: &AccountId, token: &Token, amount: Balance) {
+    let pool = campaign.pool(token).expect("Token not in campaign pool");
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "campaign_topped_up",
+            "data": {
+                "campaign_id": campaign.id,
+                "sender": sender,
+                "amount": amount.to_string(),
+                "token": token,
+                "total_pool": pool.total.0.to_string(),
+                "remaining_pool": pool.remaining.0.to_string(),
+            }
+        })
+    ));
+}
+
 pub fn emit_submission_created(submission: &Submission) {
     log!(format!(
         "EVENT_JSON:{}",
@@ -36,7 +99,15 @@ pub fn emit_submission_created(submission: &Submission) {
     ));
 }
 
-pub fn emit_payout(campaign_id: u64, submission_id: u64, receiver: AccountId, gross: Balance, fee: Balance) {
+// One event per token of the reward basket
+pub fn emit_payout(
+    campaign_id: u64,
+    submission_id: u64,
+    receiver: &AccountId,
+    token: &Token,
+    gross: Balance,
+    fee: Balance,
+) {
     log!(format!(
         "EVENT_JSON:{}",
         serde_json::json!({
@@ -47,6 +118,7 @@ pub fn emit_payout(campaign_id: u64, submission_id: u64, receiver: AccountId, gr
                 "campaign_id": campaign_id,
                 "submission_id": submission_id,
                 "receiver": receiver,
+                "token": token,
                 "gross_reward": gross.to_string(),
                 "platform_fee": fee.to_string(),
             }
@@ -54,6 +126,38 @@ pub fn emit_payout(campaign_id: u64, submission_id: u64, receiver: AccountId, gr
     ));
 }
 
+pub fn emit_pool_reserved(campaign_id: u64, submission_id: u64, amounts: &[TokenAmount]) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "pool_reserved",
+            "data": {
+                "campaign_id": campaign_id,
+                "submission_id": submission_id,
+                "amounts": token_amounts_json(amounts),
+            }
+        })
+    ));
+}
+
+pub fn emit_pool_released(campaign_id: u64, submission_id: u64, amounts: &[TokenAmount]) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "pool_released",
+            "data": {
+                "campaign_id": campaign_id,
+                "submission_id": submission_id,
+                "amounts": token_amounts_json(amounts),
+            }
+        })
+    ));
+}
+
 pub fn emit_pause_toggle(paused: bool) {
     log!(format!(
         "EVENT_JSON:{}",
@@ -66,7 +170,7 @@ pub fn emit_pause_toggle(paused: bool) {
     ));
 }
 
-pub fn emit_campaign_cancelled(campaign_id: u64, refund_amount: Balance) {
+pub fn emit_campaign_cancelled(campaign_id: u64, refunds: &[TokenAmount]) {
     log!(format!(
         "EVENT_JSON:{}",
         serde_json::json!({
@@ -75,7 +179,615 @@ pub fn emit_campaign_cancelled(campaign_id: u64, refund_amount: Balance) {
             "event": "campaign_cancelled",
             "data": {
                 "campaign_id": campaign_id,
-                "refund_amount": refund_amount.to_string(),
+                "refunds": token_amounts_json(refunds),
+            }
+        })
+    ));
+}
+
+pub fn emit_campaign_cancel_requested(campaign_id: u64, executable_at: u64) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "campaign_cancel_requested",
+            "data": {
+                "campaign_id": campaign_id,
+                "executable_at": executable_at,
+            }
+        })
+    ));
+}
+
+pub fn emit_pool_withdrawal_requested(campaign_id: u64, token: &Token, amount: Balance, executable_at: u64) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "pool_withdrawal_requested",
+            "data": {
+                "campaign_id": campaign_id,
+                "token": token,
+                "amount": amount.to_string(),
+                "executable_at": executable_at,
+            }
+        })
+    ));
+}
+
+pub fn emit_pool_withdrawn(campaign_id: u64, token: &Token, amount: Balance) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "pool_withdrawn",
+            "data": {
+                "campaign_id": campaign_id,
+                "token": token,
+                "amount": amount.to_string(),
+            }
+        })
+    ));
+}
+
+pub fn emit_payout_failed(
+    campaign_id: u64,
+    submission_id: u64,
+    receiver: &AccountId,
+    token: &Token,
+    amount: Balance,
+) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "payout_failed",
+            "data": {
+                "campaign_id": campaign_id,
+                "submission_id": submission_id,
+                "receiver": receiver,
+                "token": token,
+                "amount": amount.to_string(),
+            }
+        })
+    ));
+}
+
+pub fn emit_refund_failed(receiver: &AccountId, token: &Token, amount: Balance) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "refund_failed",
+            "data": {
+                "receiver": receiver,
+                "token": token,
+                "amount": amount.to_string(),
+            }
+        })
+    ));
+}
+
+pub fn emit_pool_withdrawal_reverted(campaign_id: u64, token: &Token, amount: Balance) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "pool_withdrawal_reverted",
+            "data": {
+                "campaign_id": campaign_id,
+                "token": token,
+                "amount": amount.to_string(),
+            }
+        })
+    ));
+}
+
+pub fn emit_campaign_finalized(campaign_id: u64, refunds: &[TokenAmount]) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "campaign_finalized",
+            "data": {
+                "campaign_id": campaign_id,
+                "refunds": token_amounts_json(refunds),
+            }
+        })
+    ));
+}
+
+pub fn emit_campaign_status_changed(campaign_id: u64, old_status: &CampaignStatus, new_status: &CampaignStatus) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "campaign_status_changed",
+            "data": {
+                "campaign_id": campaign_id,
+                "old_status": old_status,
+                "new_status": new_status,
+            }
+        })
+    ));
+}
+
+pub fn emit_campaign_invites_changed(campaign_id: u64, account_ids: &[AccountId], added: bool) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": if added { "campaign_invites_added" } else { "campaign_invites_removed" },
+            "data": {
+                "campaign_id": campaign_id,
+                "account_ids": account_ids,
+            }
+        })
+    ));
+}
+
+pub fn emit_campaign_voucher_key_set(campaign_id: u64, public_key: Option<&PublicKey>) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "campaign_voucher_key_set",
+            "data": {
+                "campaign_id": campaign_id,
+                "public_key": public_key,
+            }
+        })
+    ));
+}
+
+pub fn emit_invite_voucher_redeemed(campaign_id: u64, account_id: &AccountId, nonce: u64) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "invite_voucher_redeemed",
+            "data": {
+                "campaign_id": campaign_id,
+                "account_id": account_id,
+                "nonce": nonce,
+            }
+        })
+    ));
+}
+
+pub fn emit_campaign_ownership_proposed(campaign_id: u64, owner: &AccountId, pending_owner: Option<&AccountId>) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "campaign_ownership_proposed",
+            "data": {
+                "campaign_id": campaign_id,
+                "owner": owner,
+                "pending_owner": pending_owner,
+            }
+        })
+    ));
+}
+
+pub fn emit_campaign_ownership_transferred(campaign_id: u64, old_owner: &AccountId, new_owner: &AccountId) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "campaign_ownership_transferred",
+            "data": {
+                "campaign_id": campaign_id,
+                "old_owner": old_owner,
+                "new_owner": new_owner,
+            }
+        })
+    ));
+}
+
+pub fn emit_campaign_roles_changed(campaign_id: u64, account_id: &AccountId, roles: &[CampaignRole]) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "campaign_roles_changed",
+            "data": {
+                "campaign_id": campaign_id,
+                "account_id": account_id,
+                "roles": roles,
+            }
+        })
+    ));
+}
+
+pub fn emit_approval_policy_changed(campaign_id: u64, policy: Option<&ApprovalPolicy>) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "approval_policy_changed",
+            "data": {
+                "campaign_id": campaign_id,
+                "approvers": policy.map(|p| &p.approvers),
+                "threshold": policy.map(|p| p.threshold),
+                "amount_thresholds": policy.map(|p| token_amounts_json(&p.amount_thresholds)),
+            }
+        })
+    ));
+}
+
+pub fn emit_approval_policy_change_requested(
+    campaign_id: u64,
+    policy: Option<&ApprovalPolicy>,
+    executable_at: u64,
+) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "approval_policy_change_requested",
+            "data": {
+                "campaign_id": campaign_id,
+                "approvers": policy.map(|p| &p.approvers),
+                "threshold": policy.map(|p| p.threshold),
+                "amount_thresholds": policy.map(|p| token_amounts_json(&p.amount_thresholds)),
+                "executable_at": executable_at,
+            }
+        })
+    ));
+}
+
+pub fn emit_payout_approval_requested(campaign_id: u64, submission_id: u64, rewards: &[TokenAmount]) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "payout_approval_requested",
+            "data": {
+                "campaign_id": campaign_id,
+                "submission_id": submission_id,
+                "rewards": token_amounts_json(rewards),
+            }
+        })
+    ));
+}
+
+pub fn emit_payout_approved(submission_id: u64, approver: &AccountId, approvals: usize, threshold: u8) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "payout_approved",
+            "data": {
+                "submission_id": submission_id,
+                "approver": approver,
+                "approvals": approvals,
+                "threshold": threshold,
+            }
+        })
+    ));
+}
+
+pub fn emit_payout_approval_revoked(submission_id: u64, approver: &AccountId) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "payout_approval_revoked",
+            "data": {
+                "submission_id": submission_id,
+                "approver": approver,
+            }
+        })
+    ));
+}
+
+pub fn emit_campaign_sponsored(
+    campaign_id: u64,
+    sponsor: &AccountId,
+    token: &Token,
+    amount: Balance,
+    matching: bool,
+) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "campaign_sponsored",
+            "data": {
+                "campaign_id": campaign_id,
+                "sponsor": sponsor,
+                "token": token,
+                "amount": amount.to_string(),
+                "matching": matching,
+            }
+        })
+    ));
+}
+
+pub fn emit_sponsor_match_paid(
+    campaign_id: u64,
+    submission_id: u64,
+    sponsor: &AccountId,
+    token: &Token,
+    amount: Balance,
+) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "sponsor_match_paid",
+            "data": {
+                "campaign_id": campaign_id,
+                "submission_id": submission_id,
+                "sponsor": sponsor,
+                "token": token,
+                "amount": amount.to_string(),
+            }
+        })
+    ));
+}
+
+pub fn emit_sponsor_refunded(campaign_id: u64, sponsor: &AccountId, token: &Token, amount: Balance) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "sponsor_refunded",
+            "data": {
+                "campaign_id": campaign_id,
+                "sponsor": sponsor,
+                "token": token,
+                "amount": amount.to_string(),
+            }
+        })
+    ));
+}
+
+pub fn emit_submission_bond_set(campaign_id: u64, bond: Option<&TokenAmount>) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "submission_bond_set",
+            "data": {
+                "campaign_id": campaign_id,
+                "token": bond.map(|b| &b.token),
+                "amount": bond.map(|b| b.amount.0.to_string()),
+            }
+        })
+    ));
+}
+
+pub fn emit_researcher_bond_posted(campaign_id: u64, account_id: &AccountId, token: &Token, amount: Balance) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "researcher_bond_posted",
+            "data": {
+                "campaign_id": campaign_id,
+                "account_id": account_id,
+                "token": token,
+                "amount": amount.to_string(),
+            }
+        })
+    ));
+}
+
+pub fn emit_researcher_bond_withdrawn(campaign_id: u64, account_id: &AccountId, token: &Token, amount: Balance) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "researcher_bond_withdrawn",
+            "data": {
+                "campaign_id": campaign_id,
+                "account_id": account_id,
+                "token": token,
+                "amount": amount.to_string(),
+            }
+        })
+    ));
+}
+
+pub fn emit_submission_bond_forfeited(campaign_id: u64, submission_id: u64, bond: &TokenAmount) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "submission_bond_forfeited",
+            "data": {
+                "campaign_id": campaign_id,
+                "submission_id": submission_id,
+                "token": bond.token,
+                "amount": bond.amount.0.to_string(),
+            }
+        })
+    ));
+}
+
+pub fn emit_supported_token_set(token: &Token, config: Option<&TokenConfig>) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "supported_token_set",
+            "data": {
+                "token": token,
+                "symbol": config.map(|c| &c.symbol),
+                "decimals": config.map(|c| c.decimals),
+                "min_campaign_deposit": config.map(|c| c.min_campaign_deposit.0.to_string()),
+                "enabled": config.map_or(false, |c| c.enabled),
+            }
+        })
+    ));
+}
+
+pub fn emit_payout_mode_changed(campaign_id: u64, mode: &PayoutMode) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "payout_mode_changed",
+            "data": {
+                "campaign_id": campaign_id,
+                "payout_mode": mode,
+            }
+        })
+    ));
+}
+
+pub fn emit_rewards_claimed(account_id: &AccountId, token: &Token, amount: Balance) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "rewards_claimed",
+            "data": {
+                "account_id": account_id,
+                "token": token,
+                "amount": amount.to_string(),
+            }
+        })
+    ));
+}
+
+pub fn emit_claim_failed(account_id: &AccountId, token: &Token, amount: Balance) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "claim_failed",
+            "data": {
+                "account_id": account_id,
+                "token": token,
+                "amount": amount.to_string(),
+            }
+        })
+    ));
+}
+
+pub fn emit_storage_allowance_funded(campaign_id: u64, amount: Balance, allowance: Balance) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "storage_allowance_funded",
+            "data": {
+                "campaign_id": campaign_id,
+                "amount": amount.to_string(),
+                "storage_allowance": allowance.to_string(),
+            }
+        })
+    ));
+}
+
+pub fn emit_storage_allowance_spent(campaign_id: u64, receiver: &AccountId, token: &Token, amount: Balance) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "storage_allowance_spent",
+            "data": {
+                "campaign_id": campaign_id,
+                "receiver": receiver,
+                "token": token,
+                "amount": amount.to_string(),
+            }
+        })
+    ));
+}
+
+pub fn emit_storage_allowance_refunded(campaign_id: u64, amount: Balance) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "storage_allowance_refunded",
+            "data": {
+                "campaign_id": campaign_id,
+                "amount": amount.to_string(),
+            }
+        })
+    ));
+}
+
+pub fn emit_fee_accrued(campaign_id: u64, token: &Token, amount: Balance) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "fee_accrued",
+            "data": {
+                "campaign_id": campaign_id,
+                "token": token,
+                "amount": amount.to_string(),
+            }
+        })
+    ));
+}
+
+pub fn emit_fees_withdrawn(token: &Token, amount: Balance, treasury: &AccountId) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "fees_withdrawn",
+            "data": {
+                "token": token,
+                "amount": amount.to_string(),
+                "treasury": treasury,
+            }
+        })
+    ));
+}
+
+pub fn emit_fees_withdrawal_reverted(token: &Token, amount: Balance) {
+    log!(format!(
+        "EVENT_JSON:{}",
+        serde_json::json!({
+            "standard": "nearshield",
+            "version": "1.0.0",
+            "event": "fees_withdrawal_reverted",
+            "data": {
+                "token": token,
+                "amount": amount.to_string(),
             }
         })
     ));
@@ -4,47 +4,88 @@ use crate::*;
 
 #[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Default)]
 pub struct FinderStats {
-    pub total_rewards_earned: Balance,
+    pub total_rewards_earned: Vec<TokenAmount>, // net of fees, per token
     pub total_bugs_found: u32,
     pub total_severity_score: u32, // sum of severity levels (0-...)
+    pub total_bugs_rejected: u32,
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Default)]
 pub struct ProjectStats {
-    pub total_rewards_paid: Balance,
+    pub total_rewards_paid: Vec<TokenAmount>,
     pub total_campaigns_created: u32,
     pub total_bugs_fixed: u32,
+    pub total_pool_deposited: Vec<TokenAmount>, // creation deposits + top-ups
 }
 
 impl Contract {
     pub(crate) fn update_finder_stats(
         &mut self,
         finder: &AccountId,
-        reward: Balance,
+        rewards: &[TokenAmount],
         bugs: u32,
         severity: u8,
     ) {
         let mut stats = self.finder_stats.get(finder).unwrap_or_default();
-        stats.total_rewards_earned += reward;
+        for reward in rewards {
+            add_amount(&mut stats.total_rewards_earned, &reward.token, reward.amount.0);
+        }
         stats.total_bugs_found += bugs;
         stats.total_severity_score += severity as u32;
         self.finder_stats.insert(finder, &stats);
     }
 
+    pub(crate) fn record_finder_rejection(&mut self, finder: &AccountId) {
+        let mut stats = self.finder_stats.get(finder).unwrap_or_default();
+        stats.total_bugs_rejected += 1;
+        self.finder_stats.insert(finder, &stats);
+    }
+
     pub(crate) fn update_project_stats(
         &mut self,
         project: &AccountId,
-        paid: Balance,
+        paid: &[TokenAmount],
         campaigns: u32,
         bugs_fixed: u32,
     ) {
         let mut stats = self.project_stats.get(project).unwrap_or_default();
-        stats.total_rewards_paid += paid;
+        for reward in paid {
+            add_amount(&mut stats.total_rewards_paid, &reward.token, reward.amount.0);
+        }
         stats.total_campaigns_created += campaigns;
         stats.total_bugs_fixed += bugs_fixed;
         self.project_stats.insert(project, &stats);
     }
 
+    // Call this when a campaign pool is funded (creation or top-up)
+    pub(crate) fn record_project_deposit(&mut self, project: &AccountId, token: &Token, amount: Balance) {
+        let mut stats = self.project_stats.get(project).unwrap_or_default();
+        add_amount(&mut stats.total_pool_deposited, token, amount);
+        self.project_stats.insert(project, &stats);
+    }
+
+    // Move a campaign's contribution to the new owner so the project's
+    // leaderboard record follows it (e.g. deployer key -> multisig)
+    pub(crate) fn transfer_project_stats(&mut self, from: &AccountId, to: &AccountId, campaign: &Campaign) {
+        let mut old_stats = self.project_stats.get(from).unwrap_or_default();
+        let mut new_stats = self.project_stats.get(to).unwrap_or_default();
+        for pool in &campaign.pools {
+            // Sponsor contributions were never counted as the owner's deposits
+            let deposited = self.owner_contribution(campaign, &pool.token);
+            sub_amount(&mut old_stats.total_rewards_paid, &pool.token, pool.rewards_paid.0);
+            sub_amount(&mut old_stats.total_pool_deposited, &pool.token, deposited);
+            add_amount(&mut new_stats.total_rewards_paid, &pool.token, pool.rewards_paid.0);
+            add_amount(&mut new_stats.total_pool_deposited, &pool.token, deposited);
+        }
+        old_stats.total_campaigns_created = old_stats.total_campaigns_created.saturating_sub(1);
+        old_stats.total_bugs_fixed = old_stats.total_bugs_fixed.saturating_sub(campaign.bugs_fixed);
+        self.project_stats.insert(from, &old_stats);
+
+        new_stats.total_campaigns_created += 1;
+        new_stats.total_bugs_fixed += campaign.bugs_fixed;
+        self.project_stats.insert(to, &new_stats);
+    }
+
     // Call this when a campaign is created
     pub(crate) fn increment_project_campaigns(&mut self, project: &AccountId) {
         let mut stats = self.project_stats.get(project).unwrap_or_default();
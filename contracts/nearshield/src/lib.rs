@@ -1,5 +1,6 @@
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
-use near_sdk::collections::{LookupMap, UnorderedMap, Vector};
+use near_sdk::collections::{LookupMap, LookupSet, UnorderedMap, UnorderedSet, Vector};
+use near_sdk::json_types::U128;
 use near_sdk::serde::{Deserialize, Serialize};
 use near_sdk::{
     env, near_bindgen, AccountId, Balance, PanicOnDefault, Promise, PromiseOrValue, BorshStorageKey,
@@ -7,19 +8,39 @@ use near_sdk::{
 };
 use near_contract_standards::fungible_token::receiver::FungibleTokenReceiver;
 
+mod access;
+mod approvals;
+mod bond;
 mod campaign;
+mod claims;
+mod dao;
 mod submission;
 mod leaderboard;
 mod owner;
+mod pool;
 mod deposit;
+mod roles;
+mod sponsor;
+mod storage;
+mod tokens;
 mod events;
 mod types;
 
+use access::*;
+use approvals::*;
+use bond::*;
 use campaign::*;
+use claims::*;
+use dao::*;
 use submission::*;
 use leaderboard::*;
 use owner::*;
+use pool::*;
 use deposit::*;
+use roles::*;
+use sponsor::*;
+use storage::*;
+use tokens::*;
 use events::*;
 use types::*;
 
@@ -29,11 +50,33 @@ pub struct Contract {
     // Campaigns
     campaigns: UnorderedMap<u64, Campaign>,
     next_campaign_id: u64,
+    // Ids of public campaigns in creation order, for the listing
+    public_campaigns: Vector<u64>,
     // Submissions
     submissions: UnorderedMap<u64, Submission>,
     next_submission_id: u64,
     // Per‑campaign submission IDs
     campaign_submissions: LookupMap<u64, Vector<u64>>,
+    // Per‑campaign metadata revisions (oldest first, last = current)
+    campaign_metadata_history: LookupMap<u64, Vector<MetadataRevision>>,
+    // Private campaign allowlists
+    campaign_invites: LookupMap<u64, UnorderedSet<AccountId>>,
+    // (campaign_id, nonce) of redeemed invite vouchers
+    spent_voucher_nonces: LookupSet<(u64, u64)>,
+    // Per‑campaign triager / reviewer / payer assignments
+    campaign_roles: LookupMap<u64, UnorderedMap<AccountId, Vec<CampaignRole>>>,
+    // M-of-N approvals collected for payouts pending approval
+    payout_approvals: LookupMap<u64, Vec<PayoutApproval>>,
+    // Third‑party funding per campaign
+    campaign_sponsors: LookupMap<u64, Vec<Sponsorship>>,
+    // Unlocked researcher bonds per (campaign_id, account)
+    researcher_bonds: LookupMap<(u64, AccountId), Vec<TokenAmount>>,
+    // NEP‑141 / NEP‑245 tokens accepted for deposits
+    supported_tokens: UnorderedMap<Token, TokenConfig>,
+    // Balances each account can withdraw with `claim_rewards`
+    claimable: LookupMap<AccountId, Vec<TokenAmount>>,
+    // Platform fees collected per token, not yet withdrawn to the treasury
+    accrued_fees: UnorderedMap<Token, Balance>,
     // Leaderboards
     finder_stats: UnorderedMap<AccountId, FinderStats>,
     project_stats: UnorderedMap<AccountId, ProjectStats>,
@@ -47,8 +90,22 @@ pub struct Contract {
 #[derive(BorshSerialize, BorshStorageKey)]
 enum StorageKey {
     Campaigns,
+    PublicCampaigns,
     Submissions,
     CampaignSubmissions { campaign_id: u64 },
+    CampaignMetadataHistory,
+    CampaignMetadataRevisions { campaign_id: u64 },
+    CampaignInvites,
+    CampaignInvitees { campaign_id: u64 },
+    SpentVoucherNonces,
+    CampaignRoles,
+    CampaignRoleMembers { campaign_id: u64 },
+    PayoutApprovals,
+    CampaignSponsors,
+    ResearcherBonds,
+    SupportedTokens,
+    Claimable,
+    AccruedFees,
     FinderStats,
     ProjectStats,
 }
@@ -61,9 +118,20 @@ impl Contract {
         Self {
             campaigns: UnorderedMap::new(StorageKey::Campaigns),
             next_campaign_id: 1,
+            public_campaigns: Vector::new(StorageKey::PublicCampaigns),
             submissions: UnorderedMap::new(StorageKey::Submissions),
             next_submission_id: 1,
             campaign_submissions: LookupMap::new(b"c"),
+            campaign_metadata_history: LookupMap::new(StorageKey::CampaignMetadataHistory),
+            campaign_invites: LookupMap::new(StorageKey::CampaignInvites),
+            spent_voucher_nonces: LookupSet::new(StorageKey::SpentVoucherNonces),
+            campaign_roles: LookupMap::new(StorageKey::CampaignRoles),
+            payout_approvals: LookupMap::new(StorageKey::PayoutApprovals),
+            campaign_sponsors: LookupMap::new(StorageKey::CampaignSponsors),
+            researcher_bonds: LookupMap::new(StorageKey::ResearcherBonds),
+            supported_tokens: UnorderedMap::new(StorageKey::SupportedTokens),
+            claimable: LookupMap::new(StorageKey::Claimable),
+            accrued_fees: UnorderedMap::new(StorageKey::AccruedFees),
             finder_stats: UnorderedMap::new(StorageKey::FinderStats),
             project_stats: UnorderedMap::new(StorageKey::ProjectStats),
             admin,
@@ -77,28 +145,87 @@ impl Contract {
     // --------------
 
     pub fn get_campaign(&self, campaign_id: u64) -> Option<Campaign> {
-        self.campaigns.get(&campaign_id)
+        self.campaigns.get(&campaign_id).map(|c| self.with_current_status(c))
     }
 
-    pub fn get_campaigns(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<Campaign> {
-        let keys = self.campaigns.keys_as_vector();
+    // Public listing only; `status: Scheduled` lists upcoming campaigns.
+    // `from_index` is a position in the public campaign index: the scan starts
+    // there and stops after `limit` matches or MAX_CAMPAIGN_SCAN campaigns.
+    // Pass the returned `next_index` to continue; None means the end of the list.
+    pub fn get_campaigns(
+        &self,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+        status: Option<CampaignStatus>,
+    ) -> CampaignPage {
         let from = from_index.unwrap_or(0);
-        let limit = limit.unwrap_or(10);
-        (from..std::cmp::min(from + limit, keys.len()))
-            .filter_map(|i| keys.get(i).and_then(|id| self.campaigns.get(&id)))
-            .collect()
+        let limit = limit.unwrap_or(10) as usize;
+        let end = std::cmp::min(from.saturating_add(MAX_CAMPAIGN_SCAN), self.public_campaigns.len());
+        let mut campaigns = vec![];
+        let mut index = from;
+        while index < end && campaigns.len() < limit {
+            let campaign = self
+                .public_campaigns
+                .get(index)
+                .and_then(|id| self.campaigns.get(&id))
+                .map(|c| self.with_current_status(c));
+            index += 1;
+            if let Some(campaign) = campaign.filter(|c| status.as_ref().map_or(true, |s| &c.status == s)) {
+                campaigns.push(campaign);
+            }
+        }
+        CampaignPage {
+            campaigns,
+            next_index: if index < self.public_campaigns.len() { Some(index) } else { None },
+        }
     }
 
-    pub fn get_submission(&self, submission_id: u64) -> Option<Submission> {
-        self.submissions.get(&submission_id)
+    pub fn get_campaign_metadata_history(
+        &self,
+        campaign_id: u64,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Vec<MetadataRevision> {
+        if let Some(history) = self.campaign_metadata_history.get(&campaign_id) {
+            let from = from_index.unwrap_or(0);
+            let limit = limit.unwrap_or(10);
+            (from..std::cmp::min(from + limit, history.len()))
+                .filter_map(|i| history.get(i))
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
+    pub fn get_campaign_metadata_revision(&self, campaign_id: u64, revision: u32) -> Option<MetadataRevision> {
+        // Revisions are numbered from 1 and stored in order
+        self.campaign_metadata_history
+            .get(&campaign_id)
+            .and_then(|history| history.get((revision as u64).checked_sub(1)?))
     }
 
-    pub fn get_campaign_submissions(&self, campaign_id: u64, from: Option<u64>, limit: Option<u64>) -> Vec<Submission> {
+    // Private campaign submissions are redacted unless `viewer` may see them.
+    // Cosmetic only: `viewer` is not authenticated and contract state is public,
+    // so confidential report contents must be encrypted off-chain.
+    pub fn get_submission(&self, submission_id: u64, viewer: Option<AccountId>) -> Option<Submission> {
+        self.submissions
+            .get(&submission_id)
+            .map(|s| self.redact_submission(s, viewer.as_ref()))
+    }
+
+    pub fn get_campaign_submissions(
+        &self,
+        campaign_id: u64,
+        from: Option<u64>,
+        limit: Option<u64>,
+        viewer: Option<AccountId>,
+    ) -> Vec<Submission> {
         if let Some(list) = self.campaign_submissions.get(&campaign_id) {
             let from = from.unwrap_or(0);
             let limit = limit.unwrap_or(10);
             (from..std::cmp::min(from + limit, list.len()))
                 .filter_map(|i| list.get(i).and_then(|id| self.submissions.get(&id)))
+                .map(|s| self.redact_submission(s, viewer.as_ref()))
                 .collect()
         } else {
             vec![]
@@ -122,6 +249,13 @@ impl Contract {
             .collect()
     }
 
+    pub fn get_accrued_fees(&self) -> Vec<TokenAmount> {
+        self.accrued_fees
+            .iter()
+            .map(|(token, amount)| TokenAmount { token, amount: U128(amount) })
+            .collect()
+    }
+
     // --------------
     // Owner/admin methods (protected)
     // --------------
@@ -131,40 +265,61 @@ impl Contract {
         emit_pause_toggle(paused);
     }
 
-    pub fn withdraw_fees(&mut self, amount: Option<Balance>, token: Option<AccountId>) -> Promise {
+    // Only fees accrued in `token` can be withdrawn; None withdraws all of them
+    pub fn withdraw_fees(&mut self, amount: Option<U128>, token: Token) -> Promise {
         self.assert_admin();
         require!(!self.paused, "Contract paused");
 
-        let treasury = self.treasury.clone();
-        match token {
-            Some(token_account) => {
-                // NEP-141 transfer
-                ext_ft::ext(token_account)
-                    .with_attached_deposit(1)
-                    .with_static_gas(Gas(5 * TGAS))
-                    .ft_transfer(treasury, amount.unwrap_or(env::account_balance()), None)
-            }
-            None => {
-                // NEAR native
-                let amount = amount.unwrap_or(env::account_balance());
-                Promise::new(treasury).transfer(amount)
-            }
+        let accrued = self.accrued_fees.get(&token).unwrap_or(0);
+        let amount = amount.map_or(accrued, |a| a.0);
+        require!(amount > 0, "No fees to withdraw");
+        require!(amount <= accrued, "Amount exceeds accrued fees");
+
+        self.set_accrued_fee(&token, accrued - amount);
+        emit_fees_withdrawn(&token, amount, &self.treasury);
+        self.internal_transfer(&token, self.treasury.clone(), amount).then(
+            Self::ext(env::current_account_id())
+                .with_static_gas(Gas(5 * TGAS))
+                .on_fees_withdrawn(token, amount),
+        )
+    }
+
+    #[private]
+    pub fn on_fees_withdrawn(&mut self, token: Token, amount: Balance) -> bool {
+        if let PromiseResult::Successful(_) = env::promise_result(0) {
+            return true;
         }
+        // The transfer failed, so the fees are still here
+        let accrued = self.accrued_fees.get(&token).unwrap_or(0);
+        self.set_accrued_fee(&token, accrued + amount);
+        emit_fees_withdrawal_reverted(&token, amount);
+        false
     }
 
     // --------------
-    // NEP-141 receiver (for deposits)
+    // NEP-245 receiver (for intents / bridged multi-token deposits)
     // --------------
-    #[private]
-    pub fn process_ft_deposit(&mut self, sender_id: AccountId, amount: u128, msg: String) {
-        // Parses msg as JSON to create campaign
-        let campaign_input: CreateCampaignInput = serde_json::from_str(&msg).expect("Invalid msg format");
-        self.internal_create_campaign(
-            sender_id,
-            amount,
-            Some(env::predecessor_account_id()), // token contract
-            campaign_input,
-        );
+    // Takes one token id per transfer; the returned amounts are refunded
+    pub fn mt_on_transfer(
+        &mut self,
+        sender_id: AccountId,
+        previous_owner_ids: Vec<AccountId>,
+        token_ids: Vec<String>,
+        amounts: Vec<U128>,
+        msg: String,
+    ) -> PromiseOrValue<Vec<U128>> {
+        let _ = previous_owner_ids; // funds are credited to the sender
+        let contract_id = env::predecessor_account_id(); // multi-token contract
+        if token_ids.len() != 1 || amounts.len() != 1 {
+            log!("Refunding transfer from {}: send one token id per transfer", contract_id);
+            return PromiseOrValue::Value(amounts);
+        }
+        let token = Token::Mt {
+            contract_id,
+            token_id: token_ids[0].clone(),
+        };
+        let refund = self.internal_receive_tokens(sender_id, amounts[0].0, token, msg);
+        PromiseOrValue::Value(vec![U128(refund)])
     }
 }
 
@@ -176,13 +331,12 @@ impl FungibleTokenReceiver for Contract {
     fn ft_on_transfer(
         &mut self,
         sender_id: AccountId,
-        amount: u128,
+        amount: U128,
         msg: String,
     ) -> PromiseOrValue<U128> {
-        require!(!self.paused, "Contract paused");
-        // Use private method to process deposit
-        self.process_ft_deposit(sender_id, amount, msg);
-        PromiseOrValue::Value(U128(0)) // 0 refund = keep full amount
+        let token = Token::Ft(env::predecessor_account_id()); // token contract
+        let refund = self.internal_receive_tokens(sender_id, amount.0, token, msg);
+        PromiseOrValue::Value(U128(refund)) // 0 refund = keep full amount
     }
 }
 
@@ -191,4 +345,120 @@ impl Contract {
     fn assert_admin(&self) {
         require!(env::predecessor_account_id() == self.admin, "Only admin");
     }
+
+    pub(crate) fn internal_accrue_fee(&mut self, campaign_id: u64, token: &Token, amount: Balance) {
+        let accrued = self.accrued_fees.get(token).unwrap_or(0);
+        self.set_accrued_fee(token, accrued + amount);
+        emit_fee_accrued(campaign_id, token, amount);
+    }
+
+    fn set_accrued_fee(&mut self, token: &Token, amount: Balance) {
+        if amount == 0 {
+            self.accrued_fees.remove(token);
+        } else {
+            self.accrued_fees.insert(token, &amount);
+        }
+    }
+
+    // Bad input hands the whole amount back instead of panicking. Checks
+    // deeper down may still panic, which the token contract refunds too.
+    // Returns the unused amount.
+    fn internal_receive_tokens(&mut self, sender_id: AccountId, amount: Balance, token: Token, msg: String) -> Balance {
+        match self.internal_token_deposit(sender_id, amount, token.clone(), msg) {
+            Ok(()) => 0,
+            Err(reason) => {
+                log!("Refunding {} of {}: {}", amount, token, reason);
+                amount
+            }
+        }
+    }
+
+    // Dispatches an `ft_on_transfer` / `mt_on_transfer` msg; Err = refund the deposit with this reason
+    fn internal_token_deposit(
+        &mut self,
+        sender_id: AccountId,
+        amount: Balance,
+        token: Token,
+        msg: String,
+    ) -> Result<(), String> {
+        if self.paused {
+            return Err("Contract paused".to_string());
+        }
+        if amount == 0 {
+            return Err("Nothing deposited".to_string());
+        }
+        let token_config = self.check_token_deposit(&token)?;
+        let deposit_msg: DepositMsg =
+            serde_json::from_str(&msg).map_err(|e| format!("Invalid msg: {}", e))?;
+
+        match deposit_msg {
+            DepositMsg::CreateCampaign(input) => {
+                if input.storage_allowance.is_some() {
+                    return Err("Fund the storage allowance in NEAR with fund_storage_allowance".to_string());
+                }
+                if amount < token_config.min_campaign_deposit.0 {
+                    return Err(format!(
+                        "Below minimum campaign deposit of {} {}",
+                        token_config.min_campaign_deposit.0, token_config.symbol
+                    ));
+                }
+                self.internal_create_campaign(sender_id, amount, token, input)?;
+            }
+            DepositMsg::TopUp { campaign_id } => {
+                let campaign = self.campaign_for_deposit(campaign_id)?;
+                if sender_id != campaign.owner {
+                    return Err("Only campaign owner".to_string());
+                }
+                self.internal_top_up_campaign(campaign_id, sender_id, amount, token);
+            }
+            DepositMsg::Sponsor { campaign_id, matching } => {
+                let campaign = self.campaign_for_deposit(campaign_id)?;
+                if sender_id == campaign.owner {
+                    return Err("Campaign owner must top up instead".to_string());
+                }
+                if matching && campaign.pool(&token).is_none() {
+                    return Err("Token not in campaign pool".to_string());
+                }
+                self.check_sponsorship(campaign_id, &sender_id, &token, amount)?;
+                self.internal_sponsor_campaign(campaign_id, sender_id, amount, token, matching);
+            }
+            DepositMsg::ResearcherBond { campaign_id } => {
+                let campaign = self
+                    .campaigns
+                    .get(&campaign_id)
+                    .ok_or_else(|| "Campaign not found".to_string())?;
+                if matches!(campaign.status, CampaignStatus::Cancelled | CampaignStatus::Finalized) {
+                    return Err(format!("Campaign is {:?}", campaign.status));
+                }
+                self.internal_post_researcher_bond(campaign_id, sender_id, token, amount);
+            }
+            DepositMsg::StorageDeposit { campaign_id } => {
+                // NEP-145 deposits are staked in NEAR, a token transfer cannot cover them
+                return Err(format!(
+                    "Storage cannot be paid in tokens, attach NEAR to fund_storage_allowance for campaign {}",
+                    campaign_id
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    // Top-ups and sponsorships need an existing campaign that still takes funds
+    fn campaign_for_deposit(&self, campaign_id: u64) -> Result<Campaign, String> {
+        let campaign = self
+            .campaigns
+            .get(&campaign_id)
+            .map(|c| self.with_current_status(c))
+            .ok_or_else(|| "Campaign not found".to_string())?;
+        if !OPEN_STATUSES.contains(&campaign.status) {
+            return Err(format!("Campaign is {:?}", campaign.status));
+        }
+        Ok(campaign)
+    }
+
+    // Views report the status as of now, even if no transaction has persisted it yet
+    fn with_current_status(&self, mut campaign: Campaign) -> Campaign {
+        campaign.status = campaign.effective_status(env::block_timestamp_ms());
+        campaign
+    }
 }
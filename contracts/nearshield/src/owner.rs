@@ -9,17 +9,10 @@ impl Contract {
     }
 
     // Emergency withdraw for stuck tokens (admin only)
-    pub fn emergency_withdraw(&mut self, token: Option<AccountId>, amount: Balance, receiver: AccountId) -> Promise {
+    pub fn emergency_withdraw(&mut self, token: Token, amount: U128, receiver: AccountId) -> Promise {
         self.assert_admin();
         require!(self.paused, "Contract must be paused for emergency withdraw");
 
-        if let Some(token_account) = token {
-            ext_ft::ext(token_account)
-                .with_attached_deposit(1)
-                .with_static_gas(Gas(5 * TGAS))
-                .ft_transfer(receiver, amount, None)
-        } else {
-            Promise::new(receiver).transfer(amount)
-        }
+        self.internal_transfer(&token, receiver, amount.0)
     }
 }
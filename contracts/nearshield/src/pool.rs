@@ -0,0 +1,138 @@
+use near_sdk::{env, AccountId, Balance, Promise};
+use near_sdk::json_types::U128;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use crate::*;
+
+// Denomination of a pool or amount. Untagged, so in JSON NEAR is `null`, a
+// NEP-141 token is its contract id and a NEP-245 token is an object.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde", untagged)]
+pub enum Token {
+    Near,
+    Ft(AccountId),
+    Mt { contract_id: AccountId, token_id: String },
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Token::Near => write!(f, "NEAR"),
+            Token::Ft(contract_id) => write!(f, "{}", contract_id),
+            Token::Mt { contract_id, token_id } => write!(f, "{}:{}", contract_id, token_id),
+        }
+    }
+}
+
+// One token's share of a campaign pool
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TokenPool {
+    pub token: Token,
+    pub total: U128,
+    pub remaining: U128,
+    pub reserved: U128, // part of `remaining` held for submissions under review
+    pub awarded: U128, // part of `reserved` held for accepted rewards awaiting payout
+    pub rewards_paid: U128,
+}
+
+impl TokenPool {
+    pub fn new(token: Token) -> Self {
+        Self {
+            token,
+            total: U128(0),
+            remaining: U128(0),
+            reserved: U128(0),
+            awarded: U128(0),
+            rewards_paid: U128(0),
+        }
+    }
+
+    pub fn unreserved(&self) -> Balance {
+        self.remaining.0 - self.reserved.0
+    }
+}
+
+// An amount of one token; lists of these are used for reward baskets and stats
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TokenAmount {
+    pub token: Token,
+    pub amount: U128,
+}
+
+uint::construct_uint! {
+    pub struct U256(4);
+}
+
+// a * b / c without overflowing on large token balances
+pub fn mul_div(a: Balance, b: Balance, c: Balance) -> Balance {
+    (U256::from(a) * U256::from(b) / U256::from(c)).as_u128()
+}
+
+pub fn amount_of(amounts: &[TokenAmount], token: &Token) -> Balance {
+    amounts
+        .iter()
+        .find(|a| &a.token == token)
+        .map_or(0, |a| a.amount.0)
+}
+
+pub fn add_amount(amounts: &mut Vec<TokenAmount>, token: &Token, amount: Balance) {
+    if amount == 0 {
+        return;
+    }
+    match amounts.iter_mut().find(|a| &a.token == token) {
+        Some(entry) => entry.amount.0 += amount,
+        None => amounts.push(TokenAmount { token: token.clone(), amount: U128(amount) }),
+    }
+}
+
+// Saturating; entries that reach zero are dropped
+pub fn sub_amount(amounts: &mut Vec<TokenAmount>, token: &Token, amount: Balance) {
+    if let Some(entry) = amounts.iter_mut().find(|a| &a.token == token) {
+        entry.amount.0 = entry.amount.0.saturating_sub(amount);
+    }
+    amounts.retain(|a| a.amount.0 > 0);
+}
+
+impl Contract {
+    // Sends NEAR, a NEP-141 or a NEP-245 token; callers chain callbacks where a failure matters
+    pub(crate) fn internal_transfer(&self, token: &Token, receiver: AccountId, amount: Balance) -> Promise {
+        match token {
+            Token::Near => Promise::new(receiver).transfer(amount),
+            Token::Ft(contract_id) => ext_ft::ext(contract_id.clone())
+                .with_attached_deposit(1)
+                .with_static_gas(Gas(5 * TGAS))
+                .ft_transfer(receiver, U128(amount), None),
+            Token::Mt { contract_id, token_id } => ext_mt::ext(contract_id.clone())
+                .with_attached_deposit(1)
+                .with_static_gas(Gas(5 * TGAS))
+                .mt_transfer(receiver, token_id.clone(), U128(amount), None, None),
+        }
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    #[private]
+    pub fn on_refund_resolved(&mut self, receiver: AccountId, token: Token, amount: Balance) -> bool {
+        if let PromiseResult::Successful(_) = env::promise_result(0) {
+            return true;
+        }
+        // The funds never left; the receiver can claim them instead
+        self.internal_credit_claimable(&receiver, &token, amount);
+        emit_refund_failed(&receiver, &token, amount);
+        false
+    }
+}
+
+impl Contract {
+    // Transfer whose failure is credited to the receiver's claimable balance instead of stranding the funds
+    pub(crate) fn internal_refund(&self, token: &Token, receiver: AccountId, amount: Balance) -> Promise {
+        self.internal_transfer(token, receiver.clone(), amount).then(
+            Self::ext(env::current_account_id())
+                .with_static_gas(Gas(5 * TGAS))
+                .on_refund_resolved(receiver, token.clone(), amount),
+        )
+    }
+}
@@ -0,0 +1,83 @@
+use near_sdk::{env, require, AccountId};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use crate::*;
+
+// Per-campaign delegation; the campaign owner implicitly holds every role
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum CampaignRole {
+    Triager,  // UnderReview / Duplicate / Informative, comments
+    Reviewer, // everything a triager can do, plus accept / reject
+    Payer,    // authorizes payouts of accepted submissions
+}
+
+#[near_bindgen]
+impl Contract {
+    // Replaces the account's roles; an empty list removes the account
+    pub fn set_campaign_roles(&mut self, campaign_id: u64, account_id: AccountId, roles: Vec<CampaignRole>) {
+        self.assert_campaign_owner(campaign_id);
+        require!(!self.paused, "Contract paused");
+
+        let mut members = self
+            .campaign_roles
+            .get(&campaign_id)
+            .unwrap_or_else(|| UnorderedMap::new(StorageKey::CampaignRoleMembers { campaign_id }));
+        if roles.is_empty() {
+            members.remove(&account_id);
+        } else {
+            members.insert(&account_id, &roles);
+        }
+        self.campaign_roles.insert(&campaign_id, &members);
+        emit_campaign_roles_changed(campaign_id, &account_id, &roles);
+    }
+
+    pub fn get_campaign_roles(
+        &self,
+        campaign_id: u64,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Vec<(AccountId, Vec<CampaignRole>)> {
+        if let Some(members) = self.campaign_roles.get(&campaign_id) {
+            let from = from_index.unwrap_or(0);
+            let limit = limit.unwrap_or(10);
+            members
+                .iter()
+                .skip(from as usize)
+                .take(limit as usize)
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
+    pub fn get_account_campaign_roles(&self, campaign_id: u64, account_id: AccountId) -> Vec<CampaignRole> {
+        self.campaign_roles
+            .get(&campaign_id)
+            .and_then(|members| members.get(&account_id))
+            .unwrap_or_default()
+    }
+}
+
+impl Contract {
+    pub(crate) fn has_campaign_role(&self, campaign: &Campaign, account_id: &AccountId, role: &CampaignRole) -> bool {
+        if account_id == &campaign.owner {
+            return true;
+        }
+        self.get_account_campaign_roles(campaign.id, account_id.clone())
+            .contains(role)
+    }
+
+    // Passes if the account holds any of `roles`
+    pub(crate) fn assert_campaign_role(&self, campaign: &Campaign, account_id: &AccountId, roles: &[CampaignRole]) {
+        require!(
+            roles.iter().any(|role| self.has_campaign_role(campaign, account_id, role)),
+            format!("Requires campaign role: {:?}", roles)
+        );
+    }
+
+    pub(crate) fn has_any_campaign_role(&self, campaign: &Campaign, account_id: &AccountId) -> bool {
+        account_id == &campaign.owner
+            || !self.get_account_campaign_roles(campaign.id, account_id.clone()).is_empty()
+    }
+}
@@ -0,0 +1,236 @@
+use near_sdk::{env, require, AccountId, Balance};
+use near_sdk::json_types::U128;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::Serialize;
+use crate::*;
+
+// Sponsorships are kept in one list per campaign, so new entries are capped and
+// must clear a minimum; adding to an existing sponsorship is always allowed
+pub const MAX_SPONSORSHIPS: usize = 50;
+pub const MIN_NEAR_SPONSORSHIP: Balance = 100_000_000_000_000_000_000_000; // 0.1 NEAR
+
+// A third party's funding of a campaign, per sponsor and token
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Sponsorship {
+    pub sponsor: AccountId,
+    pub token: Token,
+    pub contributed: U128, // added to the campaign pool, refunded pro rata
+    pub matching_deposit: U128, // cap for 1:1 matching of payouts in `token`
+    pub matching_paid: U128,
+}
+
+impl Sponsorship {
+    fn matching_left(&self) -> Balance {
+        self.matching_deposit.0 - self.matching_paid.0
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    // `matching: true` holds the deposit outside the pool to match payouts 1:1
+    #[payable]
+    pub fn sponsor_campaign(&mut self, campaign_id: u64, matching: bool) {
+        require!(!self.paused, "Contract paused");
+        let deposit = env::attached_deposit();
+        require!(deposit > 0, "Attach at least 1 yoctoNEAR");
+
+        self.internal_sponsor_campaign(
+            campaign_id,
+            env::predecessor_account_id(),
+            deposit,
+            Token::Near,
+            matching,
+        );
+    }
+
+    pub fn get_campaign_sponsors(&self, campaign_id: u64) -> Vec<Sponsorship> {
+        self.campaign_sponsors.get(&campaign_id).unwrap_or_default()
+    }
+}
+
+impl Contract {
+    // Internal: used by both NEAR and FT / MT sponsorship
+    pub(crate) fn internal_sponsor_campaign(
+        &mut self,
+        campaign_id: u64,
+        sponsor: AccountId,
+        amount: Balance,
+        token: Token,
+        matching: bool,
+    ) {
+        let mut campaign = self.internal_get_campaign(campaign_id);
+        require!(sponsor != campaign.owner, "Campaign owner must top up instead");
+        campaign.assert_status(&OPEN_STATUSES);
+        if let Err(reason) = self.check_sponsorship(campaign_id, &sponsor, &token, amount) {
+            env::panic_str(&reason);
+        }
+
+        let mut sponsorships = self.campaign_sponsors.get(&campaign_id).unwrap_or_default();
+        let index = match sponsorships
+            .iter()
+            .position(|s| s.sponsor == sponsor && s.token == token)
+        {
+            Some(index) => index,
+            None => {
+                sponsorships.push(Sponsorship {
+                    sponsor: sponsor.clone(),
+                    token: token.clone(),
+                    contributed: U128(0),
+                    matching_deposit: U128(0),
+                    matching_paid: U128(0),
+                });
+                sponsorships.len() - 1
+            }
+        };
+
+        if matching {
+            // Only payouts from the campaign's own pool in this token are matched
+            require!(campaign.pool(&token).is_some(), "Token not in campaign pool");
+            sponsorships[index].matching_deposit.0 += amount;
+            add_amount(&mut campaign.matching_left, &token, amount);
+        } else {
+            sponsorships[index].contributed.0 += amount;
+            if campaign.pool(&token).is_none() {
+                campaign.pools.push(TokenPool::new(token.clone()));
+            }
+            let pool = campaign.pool_mut(&token);
+            pool.total.0 += amount;
+            pool.remaining.0 += amount;
+            campaign.sync_status(env::block_timestamp_ms());
+        }
+        self.campaigns.insert(&campaign_id, &campaign);
+        self.campaign_sponsors.insert(&campaign_id, &sponsorships);
+
+        emit_campaign_sponsored(campaign_id, &sponsor, &token, amount, matching);
+    }
+
+    // Err = the sponsorship would open a new entry below the minimum or past the cap
+    pub(crate) fn check_sponsorship(
+        &self,
+        campaign_id: u64,
+        sponsor: &AccountId,
+        token: &Token,
+        amount: Balance,
+    ) -> Result<(), String> {
+        let sponsorships = self.campaign_sponsors.get(&campaign_id).unwrap_or_default();
+        if sponsorships.iter().any(|s| &s.sponsor == sponsor && &s.token == token) {
+            return Ok(());
+        }
+        if sponsorships.len() >= MAX_SPONSORSHIPS {
+            return Err(format!("Campaign already has {} sponsorships", MAX_SPONSORSHIPS));
+        }
+        // Tokens use their minimum campaign deposit
+        let min_amount = match token {
+            Token::Near => MIN_NEAR_SPONSORSHIP,
+            _ => self.supported_tokens.get(token).map_or(0, |c| c.min_campaign_deposit.0),
+        };
+        if amount < min_amount {
+            return Err(format!("Below minimum sponsorship of {} {}", min_amount, token));
+        }
+        Ok(())
+    }
+
+    // Part of the `token` pool total funded by the owner rather than sponsors
+    pub(crate) fn owner_contribution(&self, campaign: &Campaign, token: &Token) -> Balance {
+        let total = campaign.pool(token).map_or(0, |p| p.total.0);
+        let sponsored: Balance = self
+            .campaign_sponsors
+            .get(&campaign.id)
+            .unwrap_or_default()
+            .iter()
+            .filter(|s| &s.token == token)
+            .map(|s| s.contributed.0)
+            .sum();
+        total.saturating_sub(sponsored)
+    }
+
+    // Owner withdrawals may only take the owner's pro-rata share of the withdrawable pool
+    pub(crate) fn owner_withdrawable(&self, campaign: &Campaign, token: &Token) -> Balance {
+        let total = campaign.pool(token).map_or(0, |p| p.total.0);
+        if total == 0 {
+            return 0;
+        }
+        mul_div(campaign.withdrawable_pool(token), self.owner_contribution(campaign, token), total)
+    }
+
+    // Each matching sponsor adds up to `reward` from what is left of its cap.
+    // The sponsor list is only loaded while the campaign has matching left in `token`.
+    pub(crate) fn internal_match_payout(
+        &mut self,
+        campaign: &mut Campaign,
+        submission_id: u64,
+        token: &Token,
+        reward: Balance,
+    ) -> Balance {
+        if amount_of(&campaign.matching_left, token) == 0 {
+            return 0;
+        }
+        let campaign_id = campaign.id;
+        let mut sponsorships = self.campaign_sponsors.get(&campaign_id).unwrap_or_default();
+        let mut matched_total = 0;
+        for sponsorship in sponsorships.iter_mut().filter(|s| &s.token == token) {
+            let matched = reward.min(sponsorship.matching_left());
+            if matched == 0 {
+                continue;
+            }
+            sponsorship.matching_paid.0 += matched;
+            matched_total += matched;
+            emit_sponsor_match_paid(campaign_id, submission_id, &sponsorship.sponsor, token, matched);
+        }
+        if matched_total > 0 {
+            self.campaign_sponsors.insert(&campaign_id, &sponsorships);
+            sub_amount(&mut campaign.matching_left, token, matched_total);
+        }
+        matched_total
+    }
+
+    // Splits a refund of the `token` pool between sponsors and the owner in
+    // proportion to what each put in; rounding dust goes to the owner. Sponsor
+    // shares are credited to their claimable balance rather than sent one by one.
+    pub(crate) fn internal_refund_contributors(&mut self, campaign: &Campaign, token: &Token, amount: Balance) {
+        if amount == 0 {
+            return;
+        }
+        let total = campaign.pool(token).map_or(0, |p| p.total.0);
+        let mut owner_amount = amount;
+        if total > 0 {
+            for sponsorship in self.campaign_sponsors.get(&campaign.id).unwrap_or_default() {
+                if &sponsorship.token != token || sponsorship.contributed.0 == 0 {
+                    continue;
+                }
+                let share = mul_div(amount, sponsorship.contributed.0, total).min(owner_amount);
+                if share == 0 {
+                    continue;
+                }
+                owner_amount -= share;
+                self.internal_credit_claimable(&sponsorship.sponsor, token, share);
+                emit_sponsor_refunded(campaign.id, &sponsorship.sponsor, token, share);
+            }
+        }
+        if owner_amount > 0 {
+            self.internal_refund(token, campaign.owner.clone(), owner_amount);
+        }
+    }
+
+    // Unused matching funds go back to their sponsors' claimable balances in full
+    pub(crate) fn internal_refund_matching(&mut self, campaign: &mut Campaign) {
+        if campaign.matching_left.is_empty() {
+            return;
+        }
+        campaign.matching_left.clear();
+        let campaign_id = campaign.id;
+        let mut sponsorships = self.campaign_sponsors.get(&campaign_id).unwrap_or_default();
+        for sponsorship in sponsorships.iter_mut() {
+            let refund = sponsorship.matching_left();
+            if refund == 0 {
+                continue;
+            }
+            // Shrink the cap so the funds are not matched or refunded twice
+            sponsorship.matching_deposit.0 -= refund;
+            self.internal_credit_claimable(&sponsorship.sponsor, &sponsorship.token, refund);
+            emit_sponsor_refunded(campaign_id, &sponsorship.sponsor, &sponsorship.token, refund);
+        }
+        self.campaign_sponsors.insert(&campaign_id, &sponsorships);
+    }
+}
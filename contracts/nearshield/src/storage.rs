@@ -0,0 +1,136 @@
+use near_sdk::{env, require, AccountId, Balance, PromiseOrValue};
+use near_contract_standards::storage_management::{StorageBalance, StorageBalanceBounds};
+use crate::*;
+
+// NEP-145 registration of payout receivers: before a NEP-141 payout the
+// receiver's storage balance on the token is checked, and an unregistered
+// receiver is registered from the campaign's NEAR storage allowance
+#[near_bindgen]
+impl Contract {
+    // Token-funded campaigns top the allowance up here; NEAR campaigns can also set it at creation
+    #[payable]
+    pub fn fund_storage_allowance(&mut self, campaign_id: u64) {
+        self.assert_campaign_owner(campaign_id);
+        require!(!self.paused, "Contract paused");
+        let deposit = env::attached_deposit();
+        require!(deposit > 0, "Attach at least 1 yoctoNEAR");
+
+        let mut campaign = self.internal_get_campaign(campaign_id);
+        require!(
+            !matches!(campaign.status, CampaignStatus::Cancelled | CampaignStatus::Finalized),
+            format!("Campaign is {:?}", campaign.status)
+        );
+        campaign.storage_allowance.0 += deposit;
+        self.campaigns.insert(&campaign_id, &campaign);
+        emit_storage_allowance_funded(campaign_id, deposit, campaign.storage_allowance.0);
+    }
+
+    // promise_result(0) = storage_balance_of(receiver), promise_result(1) = storage_balance_bounds()
+    #[private]
+    pub fn on_payout_storage_checked(&mut self, submission_id: u64, token: Token) -> PromiseOrValue<bool> {
+        let (submission, payout) = self.internal_pending_payout(submission_id, &token);
+        let registered = match env::promise_result(0) {
+            PromiseResult::Successful(value) => {
+                serde_json::from_slice::<Option<StorageBalance>>(&value).unwrap_or(None).is_some()
+            }
+            // The token does not implement NEP-145; try the transfer as is
+            _ => true,
+        };
+        if registered {
+            return PromiseOrValue::Promise(self.internal_transfer_payout(
+                submission_id,
+                &token,
+                submission.submitter,
+                payout.net(),
+            ));
+        }
+
+        let min_deposit = match env::promise_result(1) {
+            PromiseResult::Successful(value) => serde_json::from_slice::<StorageBalanceBounds>(&value)
+                .map(|bounds| bounds.min.0)
+                .ok(),
+            _ => None,
+        };
+        let mut campaign = self.campaigns.get(&submission.campaign_id).expect("Campaign not found");
+        let min_deposit = match min_deposit {
+            Some(min_deposit) if min_deposit <= campaign.storage_allowance.0 => min_deposit,
+            // The researcher can register with the token and call `retry_payout`
+            _ => return PromiseOrValue::Value(self.internal_resolve_payout(submission_id, token, false)),
+        };
+        campaign.storage_allowance.0 -= min_deposit;
+        self.campaigns.insert(&campaign.id, &campaign);
+        emit_storage_allowance_spent(campaign.id, &submission.submitter, &token, min_deposit);
+
+        PromiseOrValue::Promise(
+            ext_storage::ext(ft_contract_id(&token))
+                .with_attached_deposit(min_deposit)
+                .with_static_gas(Gas(10 * TGAS))
+                .storage_deposit(Some(submission.submitter), Some(true))
+                .then(
+                    Self::ext(env::current_account_id())
+                        .with_static_gas(Gas(30 * TGAS))
+                        .on_payout_storage_deposited(submission_id, token, min_deposit),
+                ),
+        )
+    }
+
+    #[private]
+    pub fn on_payout_storage_deposited(
+        &mut self,
+        submission_id: u64,
+        token: Token,
+        deposit: Balance,
+    ) -> PromiseOrValue<bool> {
+        if let PromiseResult::Successful(_) = env::promise_result(0) {
+            let (submission, payout) = self.internal_pending_payout(submission_id, &token);
+            return PromiseOrValue::Promise(self.internal_transfer_payout(
+                submission_id,
+                &token,
+                submission.submitter,
+                payout.net(),
+            ));
+        }
+
+        // The attached deposit came back with the failed receipt
+        let submission = self.submissions.get(&submission_id).expect("Submission not found");
+        let mut campaign = self.campaigns.get(&submission.campaign_id).expect("Campaign not found");
+        if matches!(campaign.status, CampaignStatus::Cancelled | CampaignStatus::Finalized) {
+            // The allowance has already been refunded
+            self.internal_refund(&Token::Near, campaign.owner.clone(), deposit);
+        } else {
+            campaign.storage_allowance.0 += deposit;
+            self.campaigns.insert(&campaign.id, &campaign);
+        }
+        PromiseOrValue::Value(self.internal_resolve_payout(submission_id, token, false))
+    }
+}
+
+impl Contract {
+    fn internal_pending_payout(&self, submission_id: u64, token: &Token) -> (Submission, TokenPayout) {
+        let submission = self.submissions.get(&submission_id).expect("Submission not found");
+        let payout = submission
+            .payouts
+            .iter()
+            .find(|p| &p.token == token && p.status == PayoutStatus::Pending)
+            .expect("No pending payout in this token")
+            .clone();
+        (submission, payout)
+    }
+
+    // Called when the campaign closes; what is left of the allowance goes back to the owner
+    pub(crate) fn internal_refund_storage_allowance(&self, campaign: &mut Campaign) {
+        let amount = std::mem::replace(&mut campaign.storage_allowance, U128(0)).0;
+        if amount == 0 {
+            return;
+        }
+        self.internal_refund(&Token::Near, campaign.owner.clone(), amount);
+        emit_storage_allowance_refunded(campaign.id, amount);
+    }
+}
+
+fn ft_contract_id(token: &Token) -> AccountId {
+    match token {
+        Token::Ft(contract_id) => contract_id.clone(),
+        _ => env::panic_str("Not a NEP-141 token"),
+    }
+}
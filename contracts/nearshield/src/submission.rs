@@ -1,7 +1,8 @@
 use near_sdk::{env, require, log, Promise};
 use crate::*;
 
-#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
 pub struct Submission {
     pub id: u64,
     pub campaign_id: u64,
@@ -10,28 +11,98 @@ pub struct Submission {
     pub description: String,        // plain text
     pub poc_link: String,
     pub severity_claim: u8,
+    pub metadata_revision: u32, // campaign scope/rules the researcher submitted under
     pub status: SubmissionStatus,
     pub review_comments: Option<String>,
-    pub reward_amount: Option<Balance>,
-    pub reviewer: Option<AccountId>,
+    pub rewards: Vec<TokenAmount>, // basket set on acceptance, at most one entry per token
+    pub reserved: Vec<TokenAmount>, // held from the campaign pools while UnderReview / AwaitingPayout
+    pub bond: Option<TokenAmount>, // researcher bond locked until the first decision
+    pub payouts: Vec<TokenPayout>, // one per basket token once the payout fires
+    pub payout_status: Option<PayoutStatus>, // Failed if any token failed, else Pending until all succeed
+    pub reviewer: Option<AccountId>, // account that made the last review decision
+    pub payout_authorized_by: Option<AccountId>,
     pub created_at: u64,
     pub updated_at: u64,
 }
 
-#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, PartialEq)]
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
 pub enum SubmissionStatus {
     Pending,
     UnderReview,
+    AwaitingPayout, // accepted by a reviewer, reward reserved until a payer authorizes it
+    PendingApproval, // authorized payout above the campaign's approval threshold
     Accepted,
     Rejected,
     Duplicate,
     Informative,
 }
 
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum PayoutStatus {
+    Pending,
+    Succeeded,
+    Credited, // Pull mode: added to the researcher's claimable balance
+    Failed, // the reward stays committed to the researcher until retried
+}
+
+// Transfer of one basket token to the researcher
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TokenPayout {
+    pub token: Token,
+    pub reward: U128, // taken from the campaign pool
+    pub matched: U128, // added by matching sponsors
+    pub fee: U128, // accrued for the treasury once the transfer succeeds or the reward is credited
+    pub status: PayoutStatus,
+}
+
+impl TokenPayout {
+    pub fn net(&self) -> Balance {
+        self.reward.0 + self.matched.0 - self.fee.0
+    }
+}
+
+impl Submission {
+    fn sync_payout_status(&mut self) {
+        let any = |status: PayoutStatus| self.payouts.iter().any(|p| p.status == status);
+        self.payout_status = if self.payouts.is_empty() {
+            None
+        } else if any(PayoutStatus::Failed) {
+            Some(PayoutStatus::Failed)
+        } else if any(PayoutStatus::Pending) {
+            Some(PayoutStatus::Pending)
+        } else if any(PayoutStatus::Credited) {
+            Some(PayoutStatus::Credited)
+        } else {
+            Some(PayoutStatus::Succeeded)
+        };
+    }
+}
+
+#[near_bindgen]
 impl Contract {
-    pub fn submit_bug(&mut self, campaign_id: u64, input: SubmitBugInput) -> u64 {
-        // ... validation unchanged
-        let submission = Submission {
+    // `voucher` is only needed for private campaigns the caller is not yet invited to
+    pub fn submit_bug(
+        &mut self,
+        campaign_id: u64,
+        input: SubmitBugInput,
+        voucher: Option<SignedInviteVoucher>,
+    ) -> u64 {
+        require!(!self.paused, "Contract paused");
+        let mut campaign = self.internal_get_campaign(campaign_id);
+        campaign.assert_status(&[CampaignStatus::Active]);
+        self.assert_can_submit(&campaign, &env::predecessor_account_id(), voucher);
+        require!(
+            campaign.severity_levels.iter().any(|s| s.id == input.severity_claim),
+            "Invalid severity claim"
+        );
+
+        let submission_id = self.next_submission_id;
+        self.next_submission_id += 1;
+
+        let mut submission = Submission {
             id: submission_id,
             campaign_id,
             submitter: env::predecessor_account_id(),
@@ -39,15 +110,24 @@ impl Contract {
             description: input.description,   // store directly
             poc_link: input.poc_link,
             severity_claim: input.severity_claim,
+            metadata_revision: campaign.metadata_revision,
             status: SubmissionStatus::Pending,
             review_comments: None,
-            reward_amount: None,
+            rewards: vec![],
+            reserved: vec![],
+            bond: None,
+            payouts: vec![],
+            payout_status: None,
             reviewer: None,
+            payout_authorized_by: None,
             created_at: env::block_timestamp_ms(),
             updated_at: env::block_timestamp_ms(),
         };
 
+        self.internal_lock_submission_bond(&campaign, &mut submission);
         self.submissions.insert(&submission_id, &submission);
+        campaign.pending_reports += 1;
+        self.campaigns.insert(&campaign_id, &campaign);
 
         // Link submission to campaign
         let mut list = self
@@ -61,89 +141,427 @@ impl Contract {
         submission_id
     }
 
+    // `rewards` is required for acceptance: one amount per campaign token,
+    // tokens left out are paid nothing
     #[payable]
     pub fn review_submission(
         &mut self,
         submission_id: u64,
         status: SubmissionStatus,
-        reward_amount: Option<Balance>,
+        rewards: Option<Vec<TokenAmount>>,
         comments: Option<String>,
     ) {
         require!(!self.paused, "Contract paused");
+        self.internal_review_submission(
+            env::predecessor_account_id(),
+            submission_id,
+            status,
+            rewards,
+            comments,
+        );
+    }
+
+    pub(crate) fn internal_review_submission(
+        &mut self,
+        actor: AccountId,
+        submission_id: u64,
+        status: SubmissionStatus,
+        rewards: Option<Vec<TokenAmount>>,
+        comments: Option<String>,
+    ) {
         let submission = self.submissions.get(&submission_id).expect("Submission not found");
-        let campaign = self.campaigns.get(&submission.campaign_id).expect("Campaign not found");
+        let mut campaign = self.internal_get_campaign(submission.campaign_id);
+        // Triagers only sort reports that no reviewer has decided yet
+        let triage = matches!(
+            status,
+            SubmissionStatus::UnderReview | SubmissionStatus::Duplicate | SubmissionStatus::Informative
+        ) && matches!(submission.status, SubmissionStatus::Pending | SubmissionStatus::UnderReview);
+        if triage {
+            self.assert_campaign_role(&campaign, &actor, &[CampaignRole::Triager, CampaignRole::Reviewer]);
+        } else {
+            self.assert_campaign_role(&campaign, &actor, &[CampaignRole::Reviewer]);
+        }
+        if campaign.status == CampaignStatus::Cancelled {
+            // Reserved submissions still get a decision after cancellation
+            require!(
+                matches!(
+                    submission.status,
+                    SubmissionStatus::UnderReview
+                        | SubmissionStatus::AwaitingPayout
+                        | SubmissionStatus::PendingApproval
+                ),
+                "Campaign is Cancelled"
+            );
+        } else {
+            campaign.assert_status(&[
+                CampaignStatus::Active,
+                CampaignStatus::Paused,
+                CampaignStatus::Expired,
+                CampaignStatus::Exhausted,
+                CampaignStatus::Cancelling,
+            ]);
+        }
+        if let Some(deadline) = campaign.review_deadline() {
+            require!(env::block_timestamp_ms() < deadline, "Review grace period is over");
+        }
         require!(
-            env::predecessor_account_id() == campaign.owner,
-            "Only campaign owner"
+            matches!(
+                submission.status,
+                SubmissionStatus::Pending
+                    | SubmissionStatus::UnderReview
+                    | SubmissionStatus::AwaitingPayout
+                    | SubmissionStatus::PendingApproval
+            ),
+            "Invalid status transition"
         );
-        require!(!campaign.cancelled, "Campaign cancelled");
         require!(
-            submission.status == SubmissionStatus::Pending || submission.status == SubmissionStatus::UnderReview,
+            !matches!(
+                status,
+                SubmissionStatus::Pending | SubmissionStatus::AwaitingPayout | SubmissionStatus::PendingApproval
+            ),
             "Invalid status transition"
         );
+        require!(
+            status != SubmissionStatus::UnderReview || submission.status == SubmissionStatus::Pending,
+            "Already under review"
+        );
 
         let mut submission = submission;
+        let was_awarded = matches!(
+            submission.status,
+            SubmissionStatus::AwaitingPayout | SubmissionStatus::PendingApproval
+        );
+        if submission.status == SubmissionStatus::Pending {
+            campaign.pending_reports -= 1;
+        }
         submission.status = status.clone();
         submission.review_comments = comments;
-        submission.reviewer = Some(env::predecessor_account_id());
+        submission.reviewer = Some(actor.clone());
         submission.updated_at = env::block_timestamp_ms();
 
-        if status == SubmissionStatus::Accepted {
-            let reward = reward_amount.expect("Reward amount required for acceptance");
-            // Validate reward <= campaign.remaining_pool and <= severity max
-            let severity_config = campaign
-                .severity_levels
-                .iter()
-                .find(|s| s.id == submission.severity_claim)
-                .expect("Invalid severity claim");
-            let max_allowed = (campaign.remaining_pool * severity_config.max_reward_pct as u128) / 100;
-            require!(reward <= max_allowed, "Reward exceeds max for this severity");
-
-            submission.reward_amount = Some(reward);
-            self.submissions.insert(&submission_id, &submission);
-            
-            // Process payout (separate function to avoid stack issues)
-            self.process_payout(&campaign, submission_id, submission.submitter, reward);
-        } else {
+        // A new decision voids approvals collected for the previous one
+        self.payout_approvals.remove(&submission_id);
+
+        // Any earlier reservation is released first; acceptance may spend it
+        let released = std::mem::take(&mut submission.reserved);
+        submission.rewards.clear();
+        for reservation in &released {
+            let pool = campaign.pool_mut(&reservation.token);
+            pool.reserved.0 -= reservation.amount.0;
+            if was_awarded {
+                pool.awarded.0 -= reservation.amount.0;
+            }
+        }
+        // Triage keeps the bond locked; any decision settles it
+        if status != SubmissionStatus::UnderReview {
+            let forfeit = status == SubmissionStatus::Rejected;
+            self.internal_settle_submission_bond(&mut campaign, &mut submission, forfeit);
+        }
+
+        match status {
+            SubmissionStatus::UnderReview => {
+                internal_reserve_for_review(&mut campaign, &mut submission);
+                self.submissions.insert(&submission_id, &submission);
+                self.campaigns.insert(&campaign.id, &campaign);
+            }
+            SubmissionStatus::Accepted => {
+                let rewards = rewards.expect("Rewards required for acceptance");
+                for (i, reward) in rewards.iter().enumerate() {
+                    require!(campaign.pool(&reward.token).is_some(), "Token not in campaign pool");
+                    require!(
+                        !rewards[..i].iter().any(|r| r.token == reward.token),
+                        "Duplicate reward token"
+                    );
+                }
+                require!(rewards.iter().any(|r| r.amount.0 > 0), "Reward basket is empty");
+
+                // Validate each token against the severity's range (never above unreserved funds).
+                // What the report itself had reserved can always be paid, even once the
+                // rest of the pool is gone, e.g. after a cancellation refund.
+                let severity = campaign.severity(submission.severity_claim).clone();
+                let mut leftover = vec![];
+                for pool in campaign.pools.iter_mut() {
+                    let reward = amount_of(&rewards, &pool.token);
+                    let (min_allowed, max_allowed) = severity.reward_range(&pool.token, pool.unreserved());
+                    let max_allowed = max_allowed.max(amount_of(&released, &pool.token));
+                    require!(reward >= min_allowed, "Reward below min for this severity");
+                    require!(reward <= max_allowed, "Reward exceeds max for this severity");
+
+                    // The reward stays reserved until the payout actually fires
+                    pool.reserved.0 += reward;
+                    pool.awarded.0 += reward;
+                    add_amount(&mut submission.rewards, &pool.token, reward);
+                    add_amount(&mut submission.reserved, &pool.token, reward);
+                    add_amount(
+                        &mut leftover,
+                        &pool.token,
+                        amount_of(&released, &pool.token).saturating_sub(reward),
+                    );
+                }
+                self.internal_release_reservation(&mut campaign, submission_id, leftover);
+
+                if !self.has_campaign_role(&campaign, &actor, &CampaignRole::Payer) {
+                    // Wait for a payer to sign off
+                    submission.status = SubmissionStatus::AwaitingPayout;
+                    self.submissions.insert(&submission_id, &submission);
+                    self.campaigns.insert(&campaign.id, &campaign);
+                    return;
+                }
+
+                submission.payout_authorized_by = Some(actor);
+                self.internal_pay_or_hold_for_approval(campaign, submission);
+            }
+            _ => {
+                if status == SubmissionStatus::Rejected {
+                    self.record_finder_rejection(&submission.submitter);
+                }
+                self.submissions.insert(&submission_id, &submission);
+                self.internal_release_reservation(&mut campaign, submission_id, released);
+                self.campaigns.insert(&campaign.id, &campaign);
+            }
+        }
+    }
+
+    pub fn authorize_payout(&mut self, submission_id: u64) {
+        require!(!self.paused, "Contract paused");
+        let mut submission = self.submissions.get(&submission_id).expect("Submission not found");
+        // Re-authorizing a pending approval re-checks it against the current policy
+        require!(
+            matches!(submission.status, SubmissionStatus::AwaitingPayout | SubmissionStatus::PendingApproval),
+            "Submission is not awaiting payout"
+        );
+        let campaign = self.internal_get_campaign(submission.campaign_id);
+        let actor = env::predecessor_account_id();
+        self.assert_campaign_role(&campaign, &actor, &[CampaignRole::Payer]);
+        // Accepted rewards stay reserved through finalization, so they can still be paid
+
+        submission.payout_authorized_by = Some(actor);
+        submission.updated_at = env::block_timestamp_ms();
+        self.internal_pay_or_hold_for_approval(campaign, submission);
+    }
+
+    // Release the reward's reservation and pay it out
+    pub(crate) fn internal_pay_reserved(&mut self, mut campaign: Campaign, mut submission: Submission) {
+        for reservation in std::mem::take(&mut submission.reserved) {
+            let pool = campaign.pool_mut(&reservation.token);
+            pool.reserved.0 -= reservation.amount.0;
+            pool.awarded.0 -= reservation.amount.0;
+        }
+        submission.status = SubmissionStatus::Accepted;
+
+        // Process payout (separate function to avoid stack issues)
+        self.process_payout(&campaign, &mut submission);
+    }
+
+    // Resends a failed token payout, e.g. after the researcher registered with the token
+    pub fn retry_payout(&mut self, submission_id: u64, token: Token) -> Promise {
+        require!(!self.paused, "Contract paused");
+        let mut submission = self.submissions.get(&submission_id).expect("Submission not found");
+        let actor = env::predecessor_account_id();
+        if actor != submission.submitter {
+            let campaign = self.internal_get_campaign(submission.campaign_id);
+            self.assert_campaign_role(&campaign, &actor, &[CampaignRole::Payer]);
+        }
+        let payout = submission
+            .payouts
+            .iter_mut()
+            .find(|p| p.token == token && p.status == PayoutStatus::Failed)
+            .expect("No failed payout in this token");
+        payout.status = PayoutStatus::Pending;
+        let payout = payout.clone();
+        submission.sync_payout_status();
+        submission.updated_at = env::block_timestamp_ms();
+        self.submissions.insert(&submission_id, &submission);
+
+        self.internal_send_payout(&submission, &payout)
+    }
+
+    #[private]
+    pub fn on_payout_resolved(&mut self, submission_id: u64, token: Token) -> bool {
+        let succeeded = matches!(env::promise_result(0), PromiseResult::Successful(_));
+        self.internal_resolve_payout(submission_id, token, succeeded)
+    }
+
+    // Any campaign role may comment without changing the status
+    pub fn comment_submission(&mut self, submission_id: u64, comments: String) {
+        require!(!self.paused, "Contract paused");
+        let mut submission = self.submissions.get(&submission_id).expect("Submission not found");
+        let campaign = self.campaigns.get(&submission.campaign_id).expect("Campaign not found");
+        require!(
+            self.has_any_campaign_role(&campaign, &env::predecessor_account_id()),
+            "Requires a campaign role"
+        );
+
+        submission.review_comments = Some(comments);
+        submission.updated_at = env::block_timestamp_ms();
+        self.submissions.insert(&submission_id, &submission);
+    }
+
+    // Cancellation safeguard: move still-pending reports of the campaign into
+    // review, checking at most `limit` submissions per call. Returns true once
+    // every submission has been checked.
+    pub(crate) fn internal_escalate_pending(&mut self, campaign: &mut Campaign, limit: u64) -> bool {
+        let list = match self.campaign_submissions.get(&campaign.id) {
+            Some(list) => list,
+            None => return true,
+        };
+        let end = std::cmp::min(campaign.escalation_cursor + limit, list.len());
+        for i in campaign.escalation_cursor..end {
+            let submission_id = list.get(i).expect("Submission not found");
+            let mut submission = self.submissions.get(&submission_id).expect("Submission not found");
+            if submission.status != SubmissionStatus::Pending {
+                continue;
+            }
+            submission.status = SubmissionStatus::UnderReview;
+            submission.updated_at = env::block_timestamp_ms();
+            campaign.pending_reports -= 1;
+            internal_reserve_for_review(campaign, &mut submission);
             self.submissions.insert(&submission_id, &submission);
         }
+        campaign.escalation_cursor = end;
+        end == list.len()
     }
 
-    fn process_payout(&mut self, campaign: &Campaign, submission_id: u64, receiver: AccountId, gross_reward: Balance) {
-        let fee = (gross_reward * campaign.platform_fee_percent as u128) / 100;
-        let net_reward = gross_reward - fee;
+    // Released funds return to the pool, or to the owner and sponsors if the campaign was cancelled
+    fn internal_release_reservation(&mut self, campaign: &mut Campaign, submission_id: u64, amounts: Vec<TokenAmount>) {
+        if amounts.is_empty() {
+            return;
+        }
+        emit_pool_released(campaign.id, submission_id, &amounts);
+        if campaign.status == CampaignStatus::Cancelled {
+            for released in &amounts {
+                campaign.pool_mut(&released.token).remaining.0 -= released.amount.0;
+                self.internal_refund_contributors(campaign, &released.token, released.amount.0);
+            }
+        }
+    }
 
-        // Update campaign remaining pool
+    // Pays every token of the basket plus any sponsor matching; the platform
+    // fee is taken per token. Push transfers resolve in `on_payout_resolved`,
+    // Pull mode credits the researcher's claimable balance instead.
+    fn process_payout(&mut self, campaign: &Campaign, submission: &mut Submission) {
         let mut campaign = campaign.clone();
-        campaign.remaining_pool -= gross_reward;
+        let push = campaign.payout_mode == PayoutMode::Push;
+
+        for reward in &submission.rewards {
+            let gross_reward = reward.amount.0;
+            let matched = self.internal_match_payout(&mut campaign, submission.id, &reward.token, gross_reward);
+            let fee = ((gross_reward + matched) * campaign.platform_fee_percent as u128) / 100;
+
+            // The reward leaves the pool now; it is committed to the researcher even if the transfer fails
+            campaign.pool_mut(&reward.token).remaining.0 -= gross_reward;
+            submission.payouts.push(TokenPayout {
+                token: reward.token.clone(),
+                reward: U128(gross_reward),
+                matched: U128(matched),
+                fee: U128(fee),
+                status: if push { PayoutStatus::Pending } else { PayoutStatus::Credited },
+            });
+        }
+        submission.sync_payout_status();
+        self.submissions.insert(&submission.id, submission);
+
+        campaign.bugs_fixed += 1;
+        campaign.sync_status(env::block_timestamp_ms());
         self.campaigns.insert(&campaign.id, &campaign);
 
-        // Update leaderboards
-        self.update_finder_stats(&receiver, net_reward, 1, submission.severity_claim);
-        self.update_project_stats(&campaign.owner, gross_reward, 0, 1);
-
-        // Transfer fee to treasury
-        if let Some(token) = &campaign.token {
-            // NEP-141 payout
-            ext_ft::ext(token.clone())
-                .with_attached_deposit(1)
-                .with_static_gas(Gas(10 * TGAS))
-                .ft_transfer(receiver.clone(), net_reward, None);
-            
-            if fee > 0 {
-                ext_ft::ext(token.clone())
-                    .with_attached_deposit(1)
-                    .with_static_gas(Gas(5 * TGAS))
-                    .ft_transfer(self.treasury.clone(), fee, None);
-            }
-        } else {
-            // NEAR payout
-            Promise::new(receiver).transfer(net_reward);
-            if fee > 0 {
-                Promise::new(self.treasury.clone()).transfer(fee);
+        // Update leaderboards; amounts are added as each payout settles
+        self.update_finder_stats(&submission.submitter, &[], 1, submission.severity_claim);
+        self.update_project_stats(&campaign.owner, &[], 0, 1);
+
+        for payout in &submission.payouts {
+            if push {
+                self.internal_send_payout(submission, payout);
+            } else {
+                self.internal_credit_claimable(&submission.submitter, &payout.token, payout.net());
+                self.internal_settle_payout(submission, payout);
             }
         }
+    }
 
-        emit_payout(campaign.id, submission_id, receiver, gross_reward, fee);
+    // Settles a pending payout, or marks it Failed for `retry_payout`
+    pub(crate) fn internal_resolve_payout(&mut self, submission_id: u64, token: Token, succeeded: bool) -> bool {
+        let mut submission = self.submissions.get(&submission_id).expect("Submission not found");
+        let payout = submission
+            .payouts
+            .iter_mut()
+            .find(|p| p.token == token && p.status == PayoutStatus::Pending)
+            .expect("No pending payout in this token");
+        payout.status = if succeeded { PayoutStatus::Succeeded } else { PayoutStatus::Failed };
+        let payout = payout.clone();
+        submission.sync_payout_status();
+        self.submissions.insert(&submission_id, &submission);
+
+        if !succeeded {
+            // Nothing left the contract; the reward stays out of the pool for a retry
+            emit_payout_failed(submission.campaign_id, submission_id, &submission.submitter, &token, payout.net());
+            return false;
+        }
+        self.internal_settle_payout(&submission, &payout);
+        true
+    }
+
+    // Called once the reward reached the researcher or their claimable balance
+    fn internal_settle_payout(&mut self, submission: &Submission, payout: &TokenPayout) {
+        let mut campaign = self.campaigns.get(&submission.campaign_id).expect("Campaign not found");
+        campaign.pool_mut(&payout.token).rewards_paid.0 += payout.reward.0;
+        self.campaigns.insert(&campaign.id, &campaign);
+        if payout.fee.0 > 0 {
+            self.internal_accrue_fee(campaign.id, &payout.token, payout.fee.0);
+        }
+
+        let receiver = &submission.submitter;
+        let net = TokenAmount { token: payout.token.clone(), amount: U128(payout.net()) };
+        let paid = TokenAmount { token: payout.token.clone(), amount: payout.reward };
+        self.update_finder_stats(receiver, &[net], 0, 0);
+        self.update_project_stats(&campaign.owner, &[paid], 0, 0);
+        emit_payout(campaign.id, submission.id, receiver, &payout.token, payout.reward.0, payout.fee.0);
+    }
+
+    // NEP-141 receivers are registered with the token first, see `on_payout_storage_checked`
+    fn internal_send_payout(&self, submission: &Submission, payout: &TokenPayout) -> Promise {
+        match &payout.token {
+            Token::Ft(contract_id) => ext_storage::ext(contract_id.clone())
+                .with_static_gas(Gas(5 * TGAS))
+                .storage_balance_of(submission.submitter.clone())
+                .and(
+                    ext_storage::ext(contract_id.clone())
+                        .with_static_gas(Gas(5 * TGAS))
+                        .storage_balance_bounds(),
+                )
+                .then(
+                    Self::ext(env::current_account_id())
+                        .with_static_gas(Gas(60 * TGAS))
+                        .on_payout_storage_checked(submission.id, payout.token.clone()),
+                ),
+            _ => self.internal_transfer_payout(submission.id, &payout.token, submission.submitter.clone(), payout.net()),
+        }
+    }
+
+    pub(crate) fn internal_transfer_payout(
+        &self,
+        submission_id: u64,
+        token: &Token,
+        receiver: AccountId,
+        amount: Balance,
+    ) -> Promise {
+        self.internal_transfer(token, receiver, amount).then(
+            Self::ext(env::current_account_id())
+                .with_static_gas(Gas(15 * TGAS))
+                .on_payout_resolved(submission_id, token.clone()),
+        )
+    }
+}
+
+// Reserve the severity maximum in every token so concurrent reviews cannot overcommit the pools
+fn internal_reserve_for_review(campaign: &mut Campaign, submission: &mut Submission) {
+    let severity = campaign.severity(submission.severity_claim).clone();
+    for pool in campaign.pools.iter_mut() {
+        let (_, reserve) = severity.reward_range(&pool.token, pool.unreserved());
+        pool.reserved.0 += reserve;
+        add_amount(&mut submission.reserved, &pool.token, reserve);
+    }
+    if !submission.reserved.is_empty() {
+        emit_pool_reserved(campaign.id, submission.id, &submission.reserved);
     }
 }
@@ -0,0 +1,57 @@
+use near_sdk::require;
+use near_sdk::json_types::U128;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use crate::*;
+
+// Admin-managed registry of NEP-141 / NEP-245 tokens accepted through
+// `ft_on_transfer` and `mt_on_transfer`
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TokenConfig {
+    pub symbol: String,
+    pub decimals: u8,
+    pub min_campaign_deposit: U128, // smallest amount a campaign can be created with
+    pub enabled: bool, // disabled tokens keep paying out existing campaigns but take no deposits
+}
+
+#[near_bindgen]
+impl Contract {
+    // Adds a token or replaces its configuration
+    pub fn set_supported_token(&mut self, token: Token, config: TokenConfig) {
+        self.assert_admin();
+        require!(!config.symbol.is_empty(), "Symbol required");
+        require!(config.decimals <= 32, "Decimals out of range");
+        require!(token != Token::Near, "NEAR is always supported");
+
+        self.supported_tokens.insert(&token, &config);
+        emit_supported_token_set(&token, Some(&config));
+    }
+
+    pub fn remove_supported_token(&mut self, token: Token) {
+        self.assert_admin();
+        require!(self.supported_tokens.remove(&token).is_some(), "Token not registered");
+        emit_supported_token_set(&token, None);
+    }
+
+    pub fn get_supported_tokens(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<(Token, TokenConfig)> {
+        let from = from_index.unwrap_or(0);
+        let limit = limit.unwrap_or(50);
+        self.supported_tokens
+            .iter()
+            .skip(from as usize)
+            .take(limit as usize)
+            .collect()
+    }
+}
+
+impl Contract {
+    // Err = the deposit is refunded with this reason
+    pub(crate) fn check_token_deposit(&self, token: &Token) -> Result<TokenConfig, String> {
+        match self.supported_tokens.get(token) {
+            Some(config) if config.enabled => Ok(config),
+            Some(_) => Err(format!("Token {} is disabled", token)),
+            None => Err(format!("Token {} is not supported", token)),
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use near_sdk::{AccountId, Balance};
+use near_sdk::AccountId;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::{Base64VecU8, U128};
 use near_sdk::serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -12,14 +14,109 @@ pub struct CreateCampaignInput {
     pub contact: Option<String>,
     pub severity_levels: Vec<SeverityConfigInput>,
     pub campaign_type: CampaignType,
+    pub start_time: Option<u64>, // defaults to now; later = scheduled campaign
     pub end_time: Option<u64>,
+    pub review_grace_period_ms: Option<u64>, // defaults to DEFAULT_REVIEW_GRACE_PERIOD_MS
+    pub cancellation_notice_ms: Option<u64>, // defaults to DEFAULT_CANCELLATION_NOTICE_MS
+    pub eligibility: Option<EligibilityRules>, // None = open to everyone
+    pub payout_mode: Option<PayoutMode>, // defaults to Push
+    pub storage_allowance: Option<U128>, // NEAR campaigns only: part of the deposit set aside for NEP-145 registrations
+}
+
+// Reputation requirements checked against the researcher's FinderStats
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(crate = "near_sdk::serde")]
+pub struct EligibilityRules {
+    pub min_accepted_bugs: Option<u32>,
+    pub min_severity_score: Option<u32>,
+    pub max_rejection_ratio_pct: Option<u8>, // rejected / (accepted + rejected), 0-100
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct EligibilityCheck {
+    pub eligible: bool,
+    pub reasons: Vec<String>, // why the account is locked out, empty if eligible
+}
+
+// Fields left as None keep their current value
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct UpdateScheduledCampaignInput {
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub severity_levels: Option<Vec<SeverityConfigInput>>,
+    pub review_grace_period_ms: Option<u64>,
+}
+
+// Fields left as None keep their current value
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct UpdateCampaignMetadataInput {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub repo_link: Option<String>,
+    pub scope: Option<String>,
+    pub rules: Option<String>,
+    pub contact: Option<String>,
+}
+
+// Accepted `msg` payloads for `ft_on_transfer` and `mt_on_transfer`, tagged
+// by `action`, e.g. {"action": "top_up", "campaign_id": 1}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde", tag = "action", rename_all = "snake_case")]
+pub enum DepositMsg {
+    CreateCampaign(CreateCampaignInput),
+    TopUp {
+        campaign_id: u64,
+    },
+    Sponsor {
+        campaign_id: u64,
+        #[serde(default)]
+        matching: bool,
+    },
+    ResearcherBond {
+        campaign_id: u64,
+    },
+    // Always refunded: storage is paid in NEAR with `fund_storage_allowance`
+    StorageDeposit {
+        campaign_id: u64,
+    },
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub struct SeverityConfigInput {
     pub name: String,
-    pub max_reward_pct: u8, // 0-100
+    #[serde(default)]
+    pub max_reward_pct: u8, // 0-100 of each token pool without a fixed reward
+    #[serde(default)]
+    pub fixed_rewards: Vec<FixedReward>, // per-token fixed mode instead of percentage
+}
+
+// Fixed reward range of a severity in one token
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct FixedReward {
+    pub token: Token,
+    #[serde(default = "zero_amount")]
+    pub min_reward: U128,
+    pub max_reward: U128,
+}
+
+fn zero_amount() -> U128 {
+    U128(0)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RewardTableEntry {
+    pub severity_id: u8,
+    pub name: String,
+    pub token: Token, // one entry per severity and pool token
+    pub fixed: bool,
+    pub min_reward: U128,
+    pub max_reward: U128, // capped by the current unreserved pool
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -29,6 +126,14 @@ pub enum CampaignType {
     Private,
 }
 
+// Push sends rewards on payout; Pull credits them for the researcher to claim
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum PayoutMode {
+    Push,
+    Pull,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub struct SubmitBugInput {
@@ -37,3 +142,21 @@ pub struct SubmitBugInput {
     pub poc_link: String,
     pub severity_claim: u8,
 }
+
+// Off-chain invitation to a private campaign, signed by the campaign's voucher key.
+// The signed message is borsh((contract_account_id, voucher)).
+#[derive(BorshSerialize, Serialize, Deserialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct InviteVoucher {
+    pub campaign_id: u64,
+    pub invitee: Option<AccountId>, // None = bearer voucher, usable by anyone once
+    pub expires_at: u64,
+    pub nonce: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SignedInviteVoucher {
+    pub voucher: InviteVoucher,
+    pub signature: Base64VecU8, // 64-byte ed25519 signature
+}
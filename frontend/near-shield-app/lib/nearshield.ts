@@ -1,12 +1,11 @@
 import { Account } from 'near-api-js';
 import { CONTRACT_ID } from '@/config/near';
+import { Token } from '@/types';
 
 export interface Campaign {
   id: number;
   owner: string;
-  token: string | null;
-  total_pool: string;
-  remaining_pool: string;
+  pools: { token: Token; total: string; remaining: string }[];
   metadata: {
     name: string;
     description: string;
@@ -16,6 +15,12 @@ export interface Campaign {
   // ... other fields
 }
 
+export function tokenLabel(token: Token): string {
+  if (token === null) return 'Ⓝ';
+  if (typeof token === 'string') return token;
+  return `${token.contract_id}:${token.token_id}`;
+}
+
 export async function viewMethod(account: Account, method: string, args = {}) {
   return await account.viewFunction(CONTRACT_ID, method, args);
 }
@@ -31,20 +36,94 @@ export async function callMethod(account: Account, method: string, args = {}, de
 }
 
 // Convenience functions
-export async function getCampaigns(account: Account, from = 0, limit = 10) {
-  return viewMethod(account, 'get_campaigns', { from_index: from, limit });
+// Resolves to { campaigns, next_index }; pass `next_index` as `from` for the next page, null = no more
+export async function getCampaigns(account: Account, from = 0, limit = 10, status?: string) {
+  return viewMethod(account, 'get_campaigns', { from_index: from, limit, status });
+}
+
+export async function getUpcomingCampaigns(account: Account, from = 0, limit = 10) {
+  return getCampaigns(account, from, limit, 'Scheduled');
 }
 
 export async function getCampaign(account: Account, id: number) {
   return viewMethod(account, 'get_campaign', { campaign_id: id });
 }
 
+export async function getRewardTable(account: Account, campaignId: number) {
+  return viewMethod(account, 'get_reward_table', { campaign_id: campaignId });
+}
+
+// [token_id, TokenConfig] pairs accepted for FT deposits
+export async function getSupportedTokens(account: Account, from = 0, limit = 50) {
+  return viewMethod(account, 'get_supported_tokens', { from_index: from, limit });
+}
+
 export async function createCampaignNear(account: Account, input: any, depositNear: string) {
   return callMethod(account, 'create_campaign_near', input, depositNear);
 }
 
-export async function submitBug(account: Account, campaignId: number, input: any) {
-  return callMethod(account, 'submit_bug', { campaign_id: campaignId, input });
+export async function topUpCampaign(account: Account, campaignId: number, depositNear: string) {
+  return callMethod(account, 'top_up_campaign', { campaign_id: campaignId }, depositNear);
+}
+
+// NEAR used to register payout receivers with NEP-141 reward tokens
+export async function fundStorageAllowance(account: Account, campaignId: number, depositNear: string) {
+  return callMethod(account, 'fund_storage_allowance', { campaign_id: campaignId }, depositNear);
+}
+
+// matching = true: deposit matches payouts 1:1 instead of joining the pool
+export async function sponsorCampaign(account: Account, campaignId: number, depositNear: string, matching = false) {
+  return callMethod(account, 'sponsor_campaign', { campaign_id: campaignId, matching }, depositNear);
+}
+
+export async function getCampaignSponsors(account: Account, campaignId: number) {
+  return viewMethod(account, 'get_campaign_sponsors', { campaign_id: campaignId });
+}
+
+// `viewer` only picks which reports are shown in full; it is not access control
+export async function getCampaignSubmissions(account: Account, campaignId: number, from = 0, limit = 10) {
+  return viewMethod(account, 'get_campaign_submissions', {
+    campaign_id: campaignId,
+    from,
+    limit,
+    viewer: account.accountId,
+  });
+}
+
+export async function checkEligibility(account: Account, campaignId: number, accountId: string) {
+  return viewMethod(account, 'check_eligibility', { campaign_id: campaignId, account_id: accountId });
+}
+
+export async function postResearcherBond(account: Account, campaignId: number, depositNear: string) {
+  return callMethod(account, 'post_researcher_bond', { campaign_id: campaignId }, depositNear);
+}
+
+export async function getResearcherBond(account: Account, campaignId: number, accountId: string) {
+  return viewMethod(account, 'get_researcher_bond', { campaign_id: campaignId, account_id: accountId });
+}
+
+export async function retryPayout(account: Account, submissionId: number, token: Token) {
+  return callMethod(account, 'retry_payout', { submission_id: submissionId, token });
+}
+
+export async function claimRewards(account: Account, token: Token) {
+  return callMethod(account, 'claim_rewards', { token });
+}
+
+export async function claimAll(account: Account) {
+  return callMethod(account, 'claim_all', {});
+}
+
+export async function getClaimable(account: Account, accountId: string) {
+  return viewMethod(account, 'get_claimable', { account_id: accountId });
+}
+
+export async function submitBug(account: Account, campaignId: number, input: any, voucher?: any) {
+  return callMethod(account, 'submit_bug', { campaign_id: campaignId, input, voucher });
+}
+
+export async function getAccruedFees(account: Account) {
+  return viewMethod(account, 'get_accrued_fees', {});
 }
 
 // ... other methods
@@ -1,7 +1,7 @@
 import { useRouter } from 'next/router'
 import { useEffect, useState } from 'react'
 import { useWalletContext } from '@/context/WalletContext'
-import { getCampaign, submitBug } from '@/lib/nearshield'
+import { getCampaign, submitBug, tokenLabel } from '@/lib/nearshield'
 import { Campaign } from '@/types'
 import { Button } from '@/components/ui/button'
 import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/card'
@@ -72,8 +72,12 @@ export default function CampaignDetail() {
               <CardTitle>Pool</CardTitle>
             </CardHeader>
             <CardContent>
-              <p className="text-2xl font-mono">{campaign.remaining_pool} Ⓝ</p>
-              <p className="text-sm text-muted-foreground mt-1">Total: {campaign.total_pool} Ⓝ</p>
+              {campaign.pools.map((pool) => (
+                <div key={tokenLabel(pool.token)} className="mb-2">
+                  <p className="text-2xl font-mono">{pool.remaining} {tokenLabel(pool.token)}</p>
+                  <p className="text-sm text-muted-foreground mt-1">Total: {pool.total} {tokenLabel(pool.token)}</p>
+                </div>
+              ))}
             </CardContent>
           </Card>
           <Card>
@@ -23,6 +23,7 @@ const formSchema = z.object({
     max_reward_pct: z.coerce.number().min(1).max(100),
   })).min(1),
   campaign_type: z.enum(['Public', 'Private']),
+  payout_mode: z.enum(['Push', 'Pull']),
   end_time: z.string().optional(),
   deposit_near: z.string().min(1, 'Deposit amount required'),
 });
@@ -41,6 +42,7 @@ export default function CreateCampaign() {
         { name: 'Low', max_reward_pct: 5 },
       ],
       campaign_type: 'Public',
+      payout_mode: 'Push',
     },
   });
 
@@ -76,6 +78,7 @@ export default function CreateCampaign() {
               <FormField ... name="scope" render={...} />
               <FormField ... name="rules" render={...} />
               <FormField ... name="campaign_type" render={...} />
+              <FormField ... name="payout_mode" render={...} />
               <FormField ... name="end_time" render={...} />
               <div>
                 <label className="text-sm font-medium">Severity Levels & Max % of Pool</label>
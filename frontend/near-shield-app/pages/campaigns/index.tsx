@@ -1,6 +1,6 @@
 import { useEffect, useState } from 'react'
 import { useWalletContext } from '@/context/WalletContext'
-import { getCampaigns } from '@/lib/nearshield'
+import { getCampaigns, tokenLabel } from '@/lib/nearshield'
 import { Campaign } from '@/types'
 import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/card'
 import { Button } from '@/components/ui/button'
@@ -13,7 +13,7 @@ export default function Campaigns() {
 
   useEffect(() => {
     if (!account) return
-    getCampaigns(account, 0, 20).then(setCampaigns).finally(() => setLoading(false))
+    getCampaigns(account, 0, 20).then((page) => setCampaigns(page.campaigns)).finally(() => setLoading(false))
   }, [account])
 
   return (
@@ -44,8 +44,8 @@ export default function Campaigns() {
             <CardContent>
               <p className="text-sm line-clamp-2 mb-4">{c.metadata.description}</p>
               <div className="flex justify-between text-sm">
-                <span>💰 Pool: {c.remaining_pool} Ⓝ</span>
-                <span>📦 {c.cancelled ? 'Cancelled' : 'Active'}</span>
+                <span>💰 Pool: {c.pools.map((p) => `${p.remaining} ${tokenLabel(p.token)}`).join(' + ')}</span>
+                <span>📦 {c.status}</span>
               </div>
               <Button asChild className="w-full mt-4">
                 <Link href={`/campaigns/${c.id}`}>View Details</Link>
@@ -1,12 +1,14 @@
 export interface Campaign {
   id: number
   owner: string
-  token: string | null
-  total_pool: string
-  remaining_pool: string
+  pending_owner?: string
+  pools: TokenPool[]
+  bugs_fixed: number
   severity_levels: SeverityLevel[]
   platform_fee_percent: number
   campaign_type: 'Public' | 'Private'
+  voucher_key?: string
+  eligibility?: EligibilityRules
   metadata: {
     name: string
     description: string
@@ -15,15 +17,92 @@ export interface Campaign {
     rules?: string
     contact?: string
   }
+  metadata_revision: number
   start_time: number
   end_time?: number
-  cancelled: boolean
+  review_grace_period_ms: number
+  cancellation_notice_ms: number
+  cancel_requested_at?: number
+  escalation_cursor: number
+  pending_reports: number
+  pending_withdrawal?: { token: Token; amount: string; requested_at: number }
+  approval_policy?: ApprovalPolicy
+  pending_approval_policy?: { policy?: ApprovalPolicy; requested_at: number }
+  submission_bond?: TokenAmount
+  matching_left: TokenAmount[]
+  payout_mode: PayoutMode
+  storage_allowance: string
+  status: CampaignStatus
+}
+
+// null = NEAR, string = NEP-141 contract, object = NEP-245 token
+export type Token = null | string | { contract_id: string; token_id: string }
+
+export interface TokenPool {
+  token: Token
+  total: string
+  remaining: string
+  reserved: string
+  awarded: string
+  rewards_paid: string
+}
+
+export interface TokenAmount {
+  token: Token
+  amount: string
+}
+
+export interface Sponsorship {
+  sponsor: string
+  token: Token
+  contributed: string
+  matching_deposit: string
+  matching_paid: string
+}
+
+export interface TokenConfig {
+  symbol: string
+  decimals: number
+  min_campaign_deposit: string
+  enabled: boolean
+}
+
+export type CampaignStatus =
+  | 'Scheduled'
+  | 'Active'
+  | 'Paused'
+  | 'Expired'
+  | 'Exhausted'
+  | 'Cancelling'
+  | 'Cancelled'
+  | 'Finalized'
+
+export interface EligibilityRules {
+  min_accepted_bugs?: number
+  min_severity_score?: number
+  max_rejection_ratio_pct?: number
 }
 
 export interface SeverityLevel {
   id: number
   name: string
   max_reward_pct: number
+  fixed_rewards: FixedReward[]
+}
+
+export interface FixedReward {
+  token: Token
+  min_reward: string
+  max_reward: string
+}
+
+export interface RewardTableEntry {
+  severity_id: number
+  name: string
+  token: Token
+  fixed: boolean
+  min_reward: string
+  max_reward: string
 }
 
 export interface Submission {
@@ -34,9 +113,36 @@ export interface Submission {
   description: string;
   poc_link: string
   severity_claim: number
-  status: 'Pending' | 'UnderReview' | 'Accepted' | 'Rejected' | 'Duplicate' | 'Informative'
-  reward_amount?: string
+  metadata_revision: number
+  status: 'Pending' | 'UnderReview' | 'AwaitingPayout' | 'PendingApproval' | 'Accepted' | 'Rejected' | 'Duplicate' | 'Informative'
+  rewards: TokenAmount[]
+  reserved: TokenAmount[]
+  bond?: TokenAmount
+  payouts: TokenPayout[]
+  payout_status?: PayoutStatus
   reviewer?: string
+  payout_authorized_by?: string
   created_at: number
   updated_at: number
 }
+
+export type PayoutMode = 'Push' | 'Pull'
+
+export type PayoutStatus = 'Pending' | 'Succeeded' | 'Credited' | 'Failed'
+
+export interface TokenPayout {
+  token: Token
+  reward: string
+  matched: string
+  fee: string
+  status: PayoutStatus
+}
+
+export type CampaignRole = 'Triager' | 'Reviewer' | 'Payer'
+
+export interface ApprovalPolicy {
+  approvers: string[]
+  threshold: number
+  amount_thresholds: TokenAmount[]
+  approval_ttl_ms: number
+}
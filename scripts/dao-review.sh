@@ -0,0 +1,105 @@
+#!/bin/bash
+# Run the DAO-owned campaign flow against contracts/mock-dao: the DAO creates
+# a campaign by proposal, a researcher submits a report and a second proposal
+# built by `get_review_proposal` accepts and pays it. Needs near-cli, curl and jq.
+#
+#   NETWORK=local scripts/dao-review.sh          # local node on :3030, master test.near
+#   MASTER=you.testnet scripts/dao-review.sh     # testnet, MASTER must be funded
+set -e
+
+NETWORK="${NETWORK:-testnet}"
+if [ "$NETWORK" = "local" ]; then
+  RPC="${RPC:-http://localhost:3030}"
+  MASTER="${MASTER:-test.near}"
+else
+  RPC="${RPC:-https://rpc.$NETWORK.near.org}"
+fi
+MASTER="${MASTER:?set MASTER to a funded $NETWORK account}"
+export NEAR_ENV=$NETWORK
+export NEAR_NETWORK=$NETWORK
+SUFFIX=$(date +%s)
+CONTRACT_ID="shield-$SUFFIX.$MASTER"
+DAO_ID="dao-$SUFFIX.$MASTER"
+RESEARCHER="researcher-$SUFFIX.$MASTER"
+
+POOL="5000000000000000000000000"   # 5 NEAR
+REWARD="1000000000000000000000000" # 1 NEAR
+
+# View call over RPC so the result comes back as plain JSON
+view() {
+  local args_base64
+  args_base64=$(echo -n "$3" | base64 -w0)
+  curl -s "$RPC" -H 'Content-Type: application/json' -d '{
+    "jsonrpc": "2.0", "id": "dontcare", "method": "query",
+    "params": {"request_type": "call_function", "finality": "optimistic",
+      "account_id": "'$1'", "method_name": "'$2'", "args_base64": "'$args_base64'"}
+  }' | jq -r '.result.result | implode'
+}
+
+# Build both contracts (Rust -> WASM)
+(cd contracts/nearshield && cargo build --target wasm32-unknown-unknown --release)
+(cd contracts/mock-dao && cargo build --target wasm32-unknown-unknown --release)
+
+near create-account $CONTRACT_ID --masterAccount $MASTER --initialBalance 10 --networkId $NETWORK
+near create-account $DAO_ID --masterAccount $MASTER --initialBalance 15 --networkId $NETWORK
+near create-account $RESEARCHER --masterAccount $MASTER --initialBalance 2 --networkId $NETWORK
+
+near deploy $CONTRACT_ID \
+  --wasmFile contracts/nearshield/target/wasm32-unknown-unknown/release/nearshield.wasm \
+  --networkId $NETWORK
+near call $CONTRACT_ID new '{"admin": "'$MASTER'", "treasury": "'$MASTER'"}' \
+  --accountId $MASTER --networkId $NETWORK
+
+near deploy $DAO_ID \
+  --wasmFile contracts/mock-dao/target/wasm32-unknown-unknown/release/mock_dao.wasm \
+  --networkId $NETWORK
+near call $DAO_ID new '{"council": ["'$MASTER'"]}' --accountId $MASTER --networkId $NETWORK
+
+# Proposal 0: the DAO creates campaign 1 and becomes its owner
+CREATE_ARGS='{"input": {
+  "name": "DAO test campaign",
+  "description": "Created by dao-review.sh",
+  "severity_levels": [{"name": "Critical", "max_reward_pct": 50}],
+  "campaign_type": "Public"
+}}'
+CREATE_KIND='{"FunctionCall": {"receiver_id": "'$CONTRACT_ID'", "actions": [{
+  "method_name": "create_campaign_near",
+  "args": "'$(echo -n "$CREATE_ARGS" | base64 -w0)'",
+  "deposit": "'$POOL'",
+  "gas": "100000000000000"
+}]}}'
+near call $DAO_ID add_proposal '{"proposal": {"description": "Create campaign", "kind": '"$CREATE_KIND"'}}' \
+  --accountId $MASTER --networkId $NETWORK
+near call $DAO_ID act_proposal '{"id": 0, "action": "VoteApprove"}' \
+  --accountId $MASTER --gas 300000000000000 --networkId $NETWORK
+
+# Submission 1
+near call $CONTRACT_ID submit_bug '{"campaign_id": 1, "input": {
+  "title": "Test report",
+  "description": "Submitted by dao-review.sh",
+  "poc_link": "https://example.com/poc",
+  "severity_claim": 0
+}}' --accountId $RESEARCHER --networkId $NETWORK
+
+# Proposal 1: accept it with a 1 NEAR reward
+REVIEWS='{"reviews": [{
+  "submission_id": 1,
+  "status": "Accepted",
+  "rewards": [{"token": null, "amount": "'$REWARD'"}],
+  "comments": "Accepted by DAO vote"
+}]}'
+REVIEW_KIND=$(view $CONTRACT_ID get_review_proposal "$REVIEWS")
+near call $DAO_ID add_proposal '{"proposal": {"description": "Review batch", "kind": '"$REVIEW_KIND"'}}' \
+  --accountId $MASTER --networkId $NETWORK
+near call $DAO_ID act_proposal '{"id": 1, "action": "VoteApprove"}' \
+  --accountId $MASTER --gas 300000000000000 --networkId $NETWORK
+
+SUBMISSION=$(view $CONTRACT_ID get_submission '{"submission_id": 1}')
+STATUS=$(echo "$SUBMISSION" | jq -r '.status')
+PAYOUT_STATUS=$(echo "$SUBMISSION" | jq -r '.payout_status')
+if [ "$STATUS" != "Accepted" ] || [ "$PAYOUT_STATUS" != "Succeeded" ]; then
+  echo "❌ Submission 1 is $STATUS with payout $PAYOUT_STATUS"
+  exit 1
+fi
+
+echo "✅ DAO proposal flow passed on $CONTRACT_ID (DAO $DAO_ID, $NETWORK)"